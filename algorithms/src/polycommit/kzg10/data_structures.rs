@@ -370,6 +370,36 @@ impl<E: PairingEngine> KZGCommitment<E> {
     pub fn is_in_correct_subgroup_assuming_on_curve(&self) -> bool {
         self.0.is_in_correct_subgroup_assuming_on_curve()
     }
+
+    /// Returns the affine `(x, y)` coordinates of the commitment, or `None` if it is the point at infinity.
+    pub fn to_affine_coordinates(&self) -> Option<(E::Fq, E::Fq)> {
+        to_affine_coordinates(&self.0)
+    }
+
+    /// Returns the commitment with the given affine `(x, y)` coordinates, or the point at infinity for `None`.
+    pub fn from_affine_coordinates(coordinates: Option<(E::Fq, E::Fq)>) -> Option<Self> {
+        from_affine_coordinates(coordinates).map(Self)
+    }
+}
+
+/// Returns the affine `(x, y)` coordinates of the given point, or `None` if it is the point at infinity.
+fn to_affine_coordinates<G: AffineCurve>(point: &G) -> Option<(G::BaseField, G::BaseField)> {
+    match point.is_zero() {
+        true => None,
+        false => Some((point.to_x_coordinate(), point.to_y_coordinate())),
+    }
+}
+
+/// Returns the point with the given affine `(x, y)` coordinates, or the point at infinity for `None`.
+/// Returns `None` if the coordinates are not on the curve, or the point is not in the prime-order subgroup.
+fn from_affine_coordinates<G: AffineCurve>(coordinates: Option<(G::BaseField, G::BaseField)>) -> Option<G> {
+    let (x, y) = match coordinates {
+        Some(coordinates) => coordinates,
+        None => return Some(G::zero()),
+    };
+    let point = G::from_x_coordinate(x, true)?;
+    let point = if point.to_y_coordinate() == y { point } else { -point };
+    (point.to_y_coordinate() == y && point.is_in_correct_subgroup_assuming_on_curve()).then_some(point)
 }
 
 impl<E: PairingEngine> ToConstraintField<E::Fq> for KZGCommitment<E> {
@@ -521,4 +551,15 @@ impl<E: PairingEngine> KZGProof<E> {
     pub fn is_hiding(&self) -> bool {
         self.random_v.is_some()
     }
+
+    /// Returns the affine `(x, y)` coordinates of the witness commitment, or `None` if it is the point at infinity.
+    /// Note: The random evaluation of a hiding proof is not included.
+    pub fn to_affine_coordinates(&self) -> Option<(E::Fq, E::Fq)> {
+        to_affine_coordinates(&self.w)
+    }
+
+    /// Returns the non-hiding proof with the given affine `(x, y)` coordinates, or the point at infinity for `None`.
+    pub fn from_affine_coordinates(coordinates: Option<(E::Fq, E::Fq)>) -> Option<Self> {
+        from_affine_coordinates(coordinates).map(|w| Self { w, random_v: None })
+    }
 }
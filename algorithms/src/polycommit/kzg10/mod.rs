@@ -659,4 +659,35 @@ mod tests {
         assert!(p.degree() > max_degree);
         assert!(KZG_Bls12_377::check_degree_is_too_large(p.degree(), powers.size()).is_err());
     }
+
+    #[test]
+    fn test_affine_coordinates() {
+        use snarkvm_curves::bls12_377::G1Projective;
+        use snarkvm_utilities::Uniform;
+
+        let rng = &mut TestRng::default();
+
+        for _ in 0..100 {
+            let point = G1Projective::rand(rng).to_affine();
+
+            // Ensure the commitment is reconstructed from its coordinates.
+            let commitment = KZGCommitment::<Bls12_377>(point);
+            let coordinates = commitment.to_affine_coordinates();
+            assert_eq!(Some((point.to_x_coordinate(), point.to_y_coordinate())), coordinates);
+            assert_eq!(Some(commitment), KZGCommitment::from_affine_coordinates(coordinates));
+
+            // Ensure the proof is reconstructed from its coordinates.
+            let proof = KZGProof::<Bls12_377> { w: point, random_v: None };
+            assert_eq!(Some(proof), KZGProof::from_affine_coordinates(proof.to_affine_coordinates()));
+
+            // Ensure coordinates that are not on the curve are rejected.
+            let (x, y) = coordinates.unwrap();
+            assert_eq!(None, KZGCommitment::<Bls12_377>::from_affine_coordinates(Some((x, y + y))));
+        }
+
+        // Ensure the point at infinity has no coordinates.
+        let commitment = KZGCommitment::<Bls12_377>::empty();
+        assert_eq!(None, commitment.to_affine_coordinates());
+        assert_eq!(Some(commitment), KZGCommitment::from_affine_coordinates(None));
+    }
 }
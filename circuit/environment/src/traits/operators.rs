@@ -29,6 +29,17 @@ pub trait Inverse {
     fn inverse(&self) -> Self::Output;
 }
 
+/// Binary operator for retrieving the lesser or greater of two values.
+pub trait MinMax<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns the lesser of `self` and `other`.
+    fn min(&self, other: &Rhs) -> Self::Output;
+
+    /// Returns the greater of `self` and `other`.
+    fn max(&self, other: &Rhs) -> Self::Output;
+}
+
 /// Unary operator for retrieving the square root of the value.
 pub trait SquareRoot {
     type Output;
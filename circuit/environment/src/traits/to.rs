@@ -66,6 +66,14 @@ pub trait ToFields {
     fn to_fields(&self) -> Vec<Self::Field>;
 }
 
+/// Unary operator for converting to an integer of type `I`.
+pub trait ToInteger<I> {
+    type Output;
+
+    /// Returns the circuit as an integer of type `I`.
+    fn to_integer(&self) -> Self::Output;
+}
+
 /// Unary operator for converting to an affine group.
 pub trait ToGroup {
     type Group: GroupTrait<Self::Scalar>;
@@ -26,7 +26,7 @@ impl<A: Aleo> Plaintext<A> {
 
         match self {
             // Halts if the value is not a struct.
-            Self::Literal(..) => A::halt("Literal is not a struct"),
+            Self::Literal(..) => bail!("Literal is not a struct"),
             // Retrieve the value of the member (from the value).
             Self::Struct(members, ..) => {
                 // Initialize the members starting from the top-level.
@@ -58,10 +58,42 @@ impl<E: Environment> AddAssign<Scalar<E>> for Scalar<E> {
 
 impl<E: Environment> AddAssign<&Scalar<E>> for Scalar<E> {
     fn add_assign(&mut self, other: &Scalar<E>) {
+        // Set the sum of `self` and `other`, in `self`.
+        *self = self.add_checked(other).0;
+    }
+}
+
+impl<E: Environment> Scalar<E> {
+    /// Returns the wrapping sum of `self` and `other`, and a flag that is `true` iff the sum
+    /// is greater than or equal to the scalar field modulus, i.e. the sum wrapped around the modulus.
+    pub fn add_checked(&self, other: &Scalar<E>) -> (Scalar<E>, Boolean<E>) {
+        // If either operand is a constant zero, return the other operand, as the sum never wraps.
+        if other.is_constant() && other.eject_value().is_zero() {
+            return (self.clone(), Boolean::constant(false));
+        }
+        if self.is_constant() && self.eject_value().is_zero() {
+            return (other.clone(), Boolean::constant(false));
+        }
+
         // Determine the variable mode.
         if self.is_constant() && other.is_constant() {
-            // Compute the sum and set the new constant in `self`.
-            *self = witness!(|self, other| self + other);
+            // Compute the sum and the carry as constants.
+            let carry = Boolean::constant((self.eject_value() + other.eject_value()) < self.eject_value());
+            (witness!(|self, other| self + other), carry)
+        } else if self.is_constant() || other.is_constant() {
+            // As one operand is a constant, the carry out of the scalar field is witnessed directly,
+            // and the wrapping sum is range-checked once, instead of extracting the bits of the sum.
+            let sum = self.to_field() + other.to_field();
+
+            // Witness the carry, which is set if the sum is greater than or equal to the scalar field modulus.
+            let carry: Boolean<E> = witness!(|self, other| (self + other) < self);
+
+            // Determine the wrapping sum, by subtracting the modulus from the sum if the carry is set.
+            let wrapping_sum = sum - Field::from_boolean(&carry) * scalar_modulus::<E>();
+
+            // Return the sum of `self` and `other`, and the carry.
+            // Note: This enforces the wrapping sum is less than the modulus, which ensures the carry is correct.
+            (Scalar::from_field(wrapping_sum), carry)
         } else {
             // Instead of adding the bits of `self` and `other` directly, the scalars are
             // converted into a field elements, and summed, before converting back to scalars.
@@ -77,50 +109,78 @@ impl<E: Environment> AddAssign<&Scalar<E>> for Scalar<E> {
             let sum = Field::from_bits_le(&bits_le);
 
             // Initialize the scalar field modulus as a constant base field variable.
-            //
-            // Note: We are reconstituting the scalar field into a base field here in order to
-            // compute the difference between the sum and modulus. This is safe as the scalar field modulus
-            // is less that the base field modulus, and thus will always fit in a base field element.
-            let modulus =
-                Field::constant(match console::FromBits::from_bits_le(&E::ScalarField::modulus().to_bits_le()) {
-                    Ok(modulus) => modulus,
-                    Err(error) => E::halt(format!("Failed to retrieve the scalar modulus as bytes: {error}")),
-                });
+            let modulus = scalar_modulus::<E>();
 
             // Determine the wrapping sum, by computing the difference between the sum and modulus, if `sum` < `modulus`.
-            let wrapping_sum = Ternary::ternary(&sum.is_less_than(&modulus), &sum, &(&sum - &modulus));
+            let is_less_than_modulus = sum.is_less_than(&modulus);
+            let wrapping_sum = Ternary::ternary(&is_less_than_modulus, &sum, &(&sum - &modulus));
 
             // Retrieve the bits of the wrapping sum.
             let bits_le = wrapping_sum.to_lower_bits_le(console::Scalar::<E::Network>::size_in_bits());
 
-            // Set the sum of `self` and `other`, in `self`.
-            *self = Scalar { field: wrapping_sum, bits_le: OnceCell::with_value(bits_le) };
+            // Return the sum of `self` and `other`, and the carry.
+            // Note: The wrapping sum is less than the modulus by construction, so the range check
+            // in `Scalar::from_field` is not required here.
+            (Scalar { field: wrapping_sum, bits_le: OnceCell::with_value(bits_le) }, !is_less_than_modulus)
         }
     }
 }
 
+/// Returns the scalar field modulus, as a constant base field element.
+///
+/// Note: We are reconstituting the scalar field into a base field here in order to
+/// compute the difference between the sum and modulus. This is safe as the scalar field modulus
+/// is less that the base field modulus, and thus will always fit in a base field element.
+pub(crate) fn scalar_modulus<E: Environment>() -> Field<E> {
+    // Note: The scalar field and base field share the same big integer representation,
+    // so the modulus is converted directly, instead of recomputing its bits on every call.
+    match E::BaseField::from_bigint(E::ScalarField::modulus()) {
+        Some(modulus) => Field::constant(console::Field::new(modulus)),
+        None => E::halt("Failed to convert the scalar field modulus into a base field element"),
+    }
+}
+
 impl<E: Environment> Metrics<dyn Add<Scalar<E>, Output = Scalar<E>>> for Scalar<E> {
-    type Case = (Mode, Mode);
+    type Case = (CircuitType<Scalar<E>>, CircuitType<Scalar<E>>);
 
     fn count(case: &Self::Case) -> Count {
-        match (case.0, case.1) {
+        match is_constant_zero(&case.0) || is_constant_zero(&case.1) {
+            true => Count::is(0, 0, 0, 0),
+            false => Scalar::<E>::estimate_add_cost(case.0.mode(), case.1.mode()),
+        }
+    }
+}
+
+impl<E: Environment> Scalar<E> {
+    /// Returns the cost of adding scalars of the given modes, without constructing them.
+    /// Note: Adding a constant zero is free, and is not reflected in this estimate.
+    pub fn estimate_add_cost(mode_a: Mode, mode_b: Mode) -> Count {
+        match (mode_a, mode_b) {
             (Mode::Constant, Mode::Constant) => Count::is(1, 0, 0, 0),
+            (Mode::Constant, _) | (_, Mode::Constant) => Count::is(1, 0, 502, 504),
             (_, _) => Count::is(1, 0, 755, 757),
         }
     }
 }
 
 impl<E: Environment> OutputMode<dyn Add<Scalar<E>, Output = Scalar<E>>> for Scalar<E> {
-    type Case = (Mode, Mode);
+    type Case = (CircuitType<Scalar<E>>, CircuitType<Scalar<E>>);
 
     fn output_mode(case: &Self::Case) -> Mode {
-        match (case.0, case.1) {
+        match (case.0.mode(), case.1.mode()) {
+            (_, mode) if is_constant_zero(&case.0) => mode,
+            (mode, _) if is_constant_zero(&case.1) => mode,
             (Mode::Constant, Mode::Constant) => Mode::Constant,
             (_, _) => Mode::Private,
         }
     }
 }
 
+/// Returns `true` if the given circuit type is a constant zero.
+fn is_constant_zero<E: Environment>(case: &CircuitType<Scalar<E>>) -> bool {
+    matches!(case, CircuitType::Constant(constant) if constant.eject_value().is_zero())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -142,10 +202,20 @@ mod tests {
         let expected = first + second;
 
         Circuit::scope(name, || {
-            let candidate = a + b;
+            let candidate = &a + &b;
             assert_eq!(expected, candidate.eject_value(), "{case}");
-            assert_count!(Add(Scalar, Scalar) => Scalar, &(mode_a, mode_b));
-            assert_output_mode!(Add(Scalar, Scalar) => Scalar, &(mode_a, mode_b), candidate);
+            assert_count!(Add(Scalar, Scalar) => Scalar, &(CircuitType::from(&a), CircuitType::from(&b)));
+            assert_output_mode!(Add(Scalar, Scalar) => Scalar, &(CircuitType::from(&a), CircuitType::from(&b)), candidate);
+            // Ensure the estimate matches the observed count, for non-zero operands.
+            if !first.is_zero() && !second.is_zero() {
+                let estimate = Scalar::<Circuit>::estimate_add_cost(mode_a, mode_b);
+                assert!(estimate.matches(
+                    Circuit::num_constants_in_scope(),
+                    Circuit::num_public_in_scope(),
+                    Circuit::num_private_in_scope(),
+                    Circuit::num_constraints_in_scope()
+                ), "{case}");
+            }
         });
     }
 
@@ -212,4 +282,110 @@ mod tests {
     fn test_scalar_private_plus_private() {
         run_test(Mode::Private, Mode::Private);
     }
+
+    fn check_add_checked(
+        first: console::Scalar<<Circuit as Environment>::Network>,
+        second: console::Scalar<<Circuit as Environment>::Network>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Scalar::<Circuit>::new(mode_a, first);
+        let b = Scalar::<Circuit>::new(mode_b, second);
+
+        Circuit::scope(format!("({first} + {second})"), || {
+            let (candidate, carry) = a.add_checked(&b);
+            assert_eq!(first + second, candidate.eject_value());
+            // The sum wraps iff the wrapping sum is less than an operand.
+            assert_eq!((first + second) < first, carry.eject_value());
+            assert_count!(Add(Scalar, Scalar) => Scalar, &(CircuitType::from(&a), CircuitType::from(&b)));
+            assert_output_mode!(Add(Scalar, Scalar) => Scalar, &(CircuitType::from(&a), CircuitType::from(&b)), candidate);
+        });
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_scalar_add_checked() {
+        let zero = console::Scalar::<<Circuit as Environment>::Network>::zero();
+        let one = console::Scalar::<<Circuit as Environment>::Network>::one();
+        let minus_one = -one;
+
+        let modes = [Mode::Constant, Mode::Public, Mode::Private];
+        for (mode_a, mode_b) in modes.into_iter().flat_map(|mode_a| modes.map(|mode_b| (mode_a, mode_b))) {
+            // Check the no-wrap cases.
+            check_add_checked(zero, zero, mode_a, mode_b);
+            check_add_checked(one, one, mode_a, mode_b);
+            check_add_checked(minus_one, zero, mode_a, mode_b);
+            // Check the wrap cases.
+            check_add_checked(minus_one, one, mode_a, mode_b);
+            check_add_checked(minus_one, minus_one, mode_a, mode_b);
+            check_add_checked(one, minus_one, mode_a, mode_b);
+        }
+    }
+
+    #[test]
+    fn test_scalar_modulus() {
+        // Ensure the modulus matches the modulus computed from its bits, across repeated calls.
+        let expected: console::Field<<Circuit as Environment>::Network> =
+            console::FromBits::from_bits_le(&<Circuit as Environment>::ScalarField::modulus().to_bits_le()).unwrap();
+        for _ in 0..3 {
+            assert_eq!(expected, scalar_modulus::<Circuit>().eject_value());
+        }
+
+        // Ensure additions that use the modulus remain correct.
+        let one = console::Scalar::<<Circuit as Environment>::Network>::one();
+        for (mode_a, mode_b) in [(Mode::Constant, Mode::Private), (Mode::Private, Mode::Private)] {
+            check_add_checked(-one, one, mode_a, mode_b);
+            check_add_checked(one, one, mode_a, mode_b);
+        }
+    }
+
+    #[test]
+    fn test_scalar_private_plus_constant_wraps() {
+        let zero = console::Scalar::<<Circuit as Environment>::Network>::zero();
+        let one = console::Scalar::<<Circuit as Environment>::Network>::one();
+        let minus_one = -one;
+
+        // Ensure the sum is correct at the boundaries of the scalar field, with the reduced counts.
+        for (first, second) in [(minus_one, one), (minus_one, minus_one), (one, minus_one), (zero, minus_one)] {
+            let a = Scalar::<Circuit>::new(Mode::Private, first);
+            let b = Scalar::<Circuit>::new(Mode::Constant, second);
+
+            Circuit::scope(format!("({first} + {second})"), || {
+                let candidate = a + b;
+                assert_eq!(first + second, candidate.eject_value());
+                assert_scope!(1, 0, 502, 504);
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_scalar_plus_constant_zero() {
+        let mut rng = TestRng::default();
+        let zero = console::Scalar::<<Circuit as Environment>::Network>::zero();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let value = Uniform::rand(&mut rng);
+            let x = Scalar::<Circuit>::new(mode, value);
+            let zero = Scalar::<Circuit>::constant(zero);
+
+            // Ensure adding a constant zero, on either side, returns `x` without any constraints.
+            Circuit::scope(format!("{mode} + 0"), || {
+                let candidate = &x + &zero;
+                assert_eq!(value, candidate.eject_value());
+                assert_eq!(mode, candidate.eject_mode());
+                assert_scope!(0, 0, 0, 0);
+            });
+            Circuit::scope(format!("0 + {mode}"), || {
+                let candidate = &zero + &x;
+                assert_eq!(value, candidate.eject_value());
+                assert_eq!(mode, candidate.eject_mode());
+                assert_scope!(0, 0, 0, 0);
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
 }
@@ -58,6 +58,28 @@ impl<E: Environment> Compare<Scalar<E>> for Scalar<E> {
     }
 }
 
+impl<E: Environment> Metrics<dyn Compare<Scalar<E>, Output = Boolean<E>>> for Scalar<E> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Count::is(1, 0, 0, 0),
+            (_, _) => Count::is(0, 0, 505, 507),
+        }
+    }
+}
+
+impl<E: Environment> OutputMode<dyn Compare<Scalar<E>, Output = Boolean<E>>> for Scalar<E> {
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Mode::Constant,
+            (_, _) => Mode::Private,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -94,6 +116,116 @@ mod tests {
         }
     }
 
+    fn check_compare(
+        name: &str,
+        first: console::Scalar<<Circuit as Environment>::Network>,
+        second: console::Scalar<<Circuit as Environment>::Network>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Scalar::<Circuit>::new(mode_a, first);
+        let b = Scalar::<Circuit>::new(mode_b, second);
+
+        // Check `is_less_than`.
+        Circuit::scope(format!("{name} {mode_a} {mode_b} is_less_than"), || {
+            let candidate = a.is_less_than(&b);
+            assert_eq!(first < second, candidate.eject_value());
+            assert_count!(Compare(Scalar, Scalar) => Boolean, &(mode_a, mode_b));
+            assert_output_mode!(Compare(Scalar, Scalar) => Boolean, &(mode_a, mode_b), candidate);
+        });
+        // Check `is_greater_than`.
+        Circuit::scope(format!("{name} {mode_a} {mode_b} is_greater_than"), || {
+            let candidate = a.is_greater_than(&b);
+            assert_eq!(first > second, candidate.eject_value());
+            assert_count!(Compare(Scalar, Scalar) => Boolean, &(mode_a, mode_b));
+            assert_output_mode!(Compare(Scalar, Scalar) => Boolean, &(mode_a, mode_b), candidate);
+        });
+        // Check `is_less_than_or_equal`.
+        Circuit::scope(format!("{name} {mode_a} {mode_b} is_less_than_or_equal"), || {
+            let candidate = a.is_less_than_or_equal(&b);
+            assert_eq!(first <= second, candidate.eject_value());
+            assert_count!(Compare(Scalar, Scalar) => Boolean, &(mode_a, mode_b));
+            assert_output_mode!(Compare(Scalar, Scalar) => Boolean, &(mode_a, mode_b), candidate);
+        });
+        // Check `is_greater_than_or_equal`.
+        Circuit::scope(format!("{name} {mode_a} {mode_b} is_greater_than_or_equal"), || {
+            let candidate = a.is_greater_than_or_equal(&b);
+            assert_eq!(first >= second, candidate.eject_value());
+            assert_count!(Compare(Scalar, Scalar) => Boolean, &(mode_a, mode_b));
+            assert_output_mode!(Compare(Scalar, Scalar) => Boolean, &(mode_a, mode_b), candidate);
+        });
+        // Check `is_equal`.
+        assert_eq!(first == second, a.is_equal(&b).eject_value());
+        Circuit::reset();
+    }
+
+    fn run_test(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        let zero = console::Scalar::zero();
+        let one = console::Scalar::one();
+        let max = -console::Scalar::one();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+            check_compare(&format!("Random {i}"), first, second, mode_a, mode_b);
+            check_compare(&format!("Equal {i}"), first, first, mode_a, mode_b);
+        }
+
+        // Check the boundary values.
+        for (first, second) in
+            [(zero, zero), (zero, one), (one, zero), (zero, max), (max, zero), (max, max), (one, max)]
+        {
+            check_compare("Boundary", first, second, mode_a, mode_b);
+        }
+    }
+
+    #[test]
+    fn test_compare_constant_constant() {
+        run_test(Mode::Constant, Mode::Constant);
+    }
+
+    #[test]
+    fn test_compare_constant_public() {
+        run_test(Mode::Constant, Mode::Public);
+    }
+
+    #[test]
+    fn test_compare_constant_private() {
+        run_test(Mode::Constant, Mode::Private);
+    }
+
+    #[test]
+    fn test_compare_public_constant() {
+        run_test(Mode::Public, Mode::Constant);
+    }
+
+    #[test]
+    fn test_compare_public_public() {
+        run_test(Mode::Public, Mode::Public);
+    }
+
+    #[test]
+    fn test_compare_public_private() {
+        run_test(Mode::Public, Mode::Private);
+    }
+
+    #[test]
+    fn test_compare_private_constant() {
+        run_test(Mode::Private, Mode::Constant);
+    }
+
+    #[test]
+    fn test_compare_private_public() {
+        run_test(Mode::Private, Mode::Public);
+    }
+
+    #[test]
+    fn test_compare_private_private() {
+        run_test(Mode::Private, Mode::Private);
+    }
+
     #[test]
     fn test_constant_is_less_than_constant() {
         check_is_less_than(Mode::Constant, Mode::Constant, 1, 0, 0, 0);
@@ -0,0 +1,255 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Div<Scalar<E>> for Scalar<E> {
+    type Output = Scalar<E>;
+
+    fn div(self, other: Scalar<E>) -> Self::Output {
+        self / &other
+    }
+}
+
+impl<E: Environment> Div<&Scalar<E>> for Scalar<E> {
+    type Output = Scalar<E>;
+
+    fn div(self, other: &Scalar<E>) -> Self::Output {
+        &self / other
+    }
+}
+
+impl<E: Environment> Div<Scalar<E>> for &Scalar<E> {
+    type Output = Scalar<E>;
+
+    fn div(self, other: Scalar<E>) -> Self::Output {
+        self / &other
+    }
+}
+
+impl<E: Environment> Div<&Scalar<E>> for &Scalar<E> {
+    type Output = Scalar<E>;
+
+    fn div(self, other: &Scalar<E>) -> Self::Output {
+        let mut output = self.clone();
+        output /= other;
+        output
+    }
+}
+
+impl<E: Environment> DivAssign<Scalar<E>> for Scalar<E> {
+    fn div_assign(&mut self, other: Scalar<E>) {
+        *self /= &other;
+    }
+}
+
+impl<E: Environment> DivAssign<&Scalar<E>> for Scalar<E> {
+    fn div_assign(&mut self, other: &Scalar<E>) {
+        match other.inverse() {
+            // Set the quotient of `self` and `other`, in `self`.
+            Ok(inverse) => *self = self.mul_mod(&inverse),
+            // If `other` is a constant zero, halt since the inverse of zero is undefined.
+            Err(_) => E::halt("Attempted to divide by zero."),
+        }
+    }
+}
+
+impl<E: Environment> Metrics<dyn Div<Scalar<E>, Output = Scalar<E>>> for Scalar<E> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        match case {
+            // The inverse and product are computed as constants.
+            (Mode::Constant, Mode::Constant) => Count::is(2, 0, 0, 0),
+            // The inverse costs 1883 constraints, and the product of the constant with the inverse costs
+            // 1371 constraints, as it reuses the bits of the inverse (instead of 1874 constraints below).
+            (Mode::Constant, _) => Count::is(262, 0, 3238, 3254),
+            // The inverse is a constant, and the product with the constant costs 1874 constraints,
+            // as it decomposes `self` into bits (instead of 1371 constraints above).
+            (_, Mode::Constant) => Count::is(257, 0, 1865, 1874),
+            // The inverse costs 1883 constraints, and the product costs 1883 constraints.
+            (_, _) => Count::is(11, 0, 3748, 3766),
+        }
+    }
+}
+
+impl<E: Environment> OutputMode<dyn Div<Scalar<E>, Output = Scalar<E>>> for Scalar<E> {
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case {
+            (Mode::Constant, Mode::Constant) => Mode::Constant,
+            (_, _) => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::{Circuit, assert_count_fails};
+
+    const ITERATIONS: u64 = 16;
+
+    fn check_div(
+        name: &str,
+        first: &console::Scalar<<Circuit as Environment>::Network>,
+        second: &console::Scalar<<Circuit as Environment>::Network>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = &Scalar::<Circuit>::new(mode_a, *first);
+        let b = &Scalar::<Circuit>::new(mode_b, *second);
+
+        match second.is_zero() {
+            true => match mode_b.is_constant() {
+                true => {
+                    let result = std::panic::catch_unwind(|| Scalar::div(a.clone(), b));
+                    assert!(result.is_err());
+                }
+                false => {
+                    Circuit::scope(name, || {
+                        let _ = a / b;
+                        assert_count_fails!(Div(Scalar, Scalar) => Scalar, &(mode_a, mode_b));
+                    });
+                }
+            },
+            false => {
+                let expected = *first * second.inverse().unwrap();
+                Circuit::scope(name, || {
+                    let candidate = a / b;
+                    assert_eq!(expected, candidate.eject_value(), "({} / {})", a.eject_value(), b.eject_value());
+                    assert_eq!(*first, candidate.eject_value() * *second);
+                    assert_count!(Div(Scalar, Scalar) => Scalar, &(mode_a, mode_b));
+                    assert_output_mode!(Div(Scalar, Scalar) => Scalar, &(mode_a, mode_b), candidate);
+                });
+            }
+        }
+        Circuit::reset();
+    }
+
+    fn check_div_assign(
+        name: &str,
+        first: &console::Scalar<<Circuit as Environment>::Network>,
+        second: &console::Scalar<<Circuit as Environment>::Network>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = &Scalar::<Circuit>::new(mode_a, *first);
+        let b = &Scalar::<Circuit>::new(mode_b, *second);
+
+        match second.is_zero() {
+            true => match mode_b.is_constant() {
+                true => {
+                    let result = std::panic::catch_unwind(|| Scalar::div_assign(&mut a.clone(), b));
+                    assert!(result.is_err());
+                }
+                false => {
+                    Circuit::scope(name, || {
+                        let mut candidate = a.clone();
+                        candidate /= b;
+                        assert_count_fails!(Div(Scalar, Scalar) => Scalar, &(mode_a, mode_b));
+                    });
+                }
+            },
+            false => {
+                let expected = *first * second.inverse().unwrap();
+                Circuit::scope(name, || {
+                    let mut candidate = a.clone();
+                    candidate /= b;
+                    assert_eq!(expected, candidate.eject_value(), "({} /= {})", a.eject_value(), b.eject_value());
+                    assert_eq!(*first, candidate.eject_value() * *second);
+                    assert_count!(Div(Scalar, Scalar) => Scalar, &(mode_a, mode_b));
+                    assert_output_mode!(Div(Scalar, Scalar) => Scalar, &(mode_a, mode_b), candidate);
+                });
+            }
+        }
+        Circuit::reset();
+    }
+
+    fn run_test(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("Div: a / b {i}");
+            check_div(&name, &first, &second, mode_a, mode_b);
+            let name = format!("DivAssign: a / b {i}");
+            check_div_assign(&name, &first, &second, mode_a, mode_b);
+
+            // Check division by one.
+            let one = console::Scalar::<<Circuit as Environment>::Network>::one();
+            let name = format!("Div By One {i}");
+            check_div(&name, &first, &one, mode_a, mode_b);
+            let name = format!("DivAssign By One {i}");
+            check_div_assign(&name, &first, &one, mode_a, mode_b);
+
+            // Check division by zero.
+            let zero = console::Scalar::<<Circuit as Environment>::Network>::zero();
+            let name = format!("Div By Zero {i}");
+            check_div(&name, &first, &zero, mode_a, mode_b);
+            let name = format!("DivAssign By Zero {i}");
+            check_div_assign(&name, &first, &zero, mode_a, mode_b);
+        }
+    }
+
+    #[test]
+    fn test_constant_div_constant() {
+        run_test(Mode::Constant, Mode::Constant);
+    }
+
+    #[test]
+    fn test_constant_div_public() {
+        run_test(Mode::Constant, Mode::Public);
+    }
+
+    #[test]
+    fn test_constant_div_private() {
+        run_test(Mode::Constant, Mode::Private);
+    }
+
+    #[test]
+    fn test_public_div_constant() {
+        run_test(Mode::Public, Mode::Constant);
+    }
+
+    #[test]
+    fn test_public_div_public() {
+        run_test(Mode::Public, Mode::Public);
+    }
+
+    #[test]
+    fn test_public_div_private() {
+        run_test(Mode::Public, Mode::Private);
+    }
+
+    #[test]
+    fn test_private_div_constant() {
+        run_test(Mode::Private, Mode::Constant);
+    }
+
+    #[test]
+    fn test_private_div_public() {
+        run_test(Mode::Private, Mode::Public);
+    }
+
+    #[test]
+    fn test_private_div_private() {
+        run_test(Mode::Private, Mode::Private);
+    }
+}
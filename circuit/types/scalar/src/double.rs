@@ -0,0 +1,145 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::add::scalar_modulus;
+
+impl<E: Environment> Double for Scalar<E> {
+    type Output = Scalar<E>;
+
+    /// Returns `2 * self`, with a single reduction by the scalar field modulus.
+    fn double(&self) -> Self::Output {
+        // If `self` is a constant, compute the double as a constant.
+        if self.is_constant() {
+            return witness!(|self| self.double());
+        }
+
+        // Double `self` in the base field.
+        // Note: This is safe as the base field is larger than twice the scalar field.
+        let double = self.to_field().double();
+
+        // Witness the carry, which is set if the double is greater than or equal to the scalar field modulus.
+        let carry: Boolean<E> = witness!(|self| self.double() < self);
+
+        // Determine the wrapping double, by subtracting the modulus from the double if the carry is set.
+        let wrapping_double = double - Field::from_boolean(&carry) * scalar_modulus::<E>();
+
+        // Return the double of `self`.
+        // Note: This enforces the wrapping double is less than the modulus, which ensures the carry is correct.
+        Scalar::from_field(wrapping_double)
+    }
+}
+
+impl<E: Environment> Metrics<dyn Double<Output = Scalar<E>>> for Scalar<E> {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        Scalar::<E>::estimate_double_cost(*case)
+    }
+}
+
+impl<E: Environment> Scalar<E> {
+    /// Returns the cost of doubling a scalar of the given mode, without constructing it.
+    pub fn estimate_double_cost(mode: Mode) -> Count {
+        match mode {
+            Mode::Constant => Count::is(1, 0, 0, 0),
+            _ => Count::is(1, 0, 502, 504),
+        }
+    }
+}
+
+impl<E: Environment> OutputMode<dyn Double<Output = Scalar<E>>> for Scalar<E> {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case {
+            Mode::Constant => Mode::Constant,
+            _ => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 128;
+
+    fn check_double(name: &str, given: console::Scalar<<Circuit as Environment>::Network>, mode: Mode) {
+        let candidate = Scalar::<Circuit>::new(mode, given);
+
+        Circuit::scope(name, || {
+            let result = candidate.double();
+            assert_eq!(given + given, result.eject_value());
+            assert_count!(Double(Scalar) => Scalar, &mode);
+            let estimate = Scalar::<Circuit>::estimate_double_cost(mode);
+            assert!(estimate.matches(
+                Circuit::num_constants_in_scope(),
+                Circuit::num_public_in_scope(),
+                Circuit::num_private_in_scope(),
+                Circuit::num_constraints_in_scope()
+            ));
+            assert_output_mode!(Double(Scalar) => Scalar, &mode, result);
+        });
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_double() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for i in 0..ITERATIONS {
+                check_double(&format!("Double: {mode} {i}"), Uniform::rand(&mut rng), mode);
+            }
+        }
+    }
+
+    #[test]
+    fn test_double_boundaries() {
+        let zero = console::Scalar::<<Circuit as Environment>::Network>::zero();
+        let one = console::Scalar::<<Circuit as Environment>::Network>::one();
+        let half = console::Scalar::<<Circuit as Environment>::Network>::from_str(
+            "1055557718678546303031103117347693316419435463204204097596842623197360680191scalar",
+        )
+        .unwrap();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for given in [zero, one, -one, half, half + one, -half] {
+                check_double(&format!("Double: {mode} {given}"), given, mode);
+            }
+        }
+    }
+
+    #[test]
+    fn test_double_is_cheaper_than_add() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Public, Mode::Private] {
+            let given = Scalar::<Circuit>::new(mode, Uniform::rand(&mut rng));
+
+            // Count the constraints of a double, and of the generic addition.
+            Circuit::scope("Double", || given.double());
+            let double = Circuit::num_constraints();
+            Circuit::scope("Add", || given.clone() + given.clone());
+            let add = Circuit::num_constraints() - double;
+            assert!(double < add, "{double} < {add}");
+            Circuit::reset();
+        }
+    }
+}
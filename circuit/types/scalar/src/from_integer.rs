@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Scalar<E> {
+    /// Initializes a scalar from a `u64`, caching its bits.
+    pub fn from_u64(mode: Mode, value: u64) -> Self {
+        Self::from_unsigned_bits_le(mode, &value.to_bits_le())
+    }
+
+    /// Initializes a scalar from an integer of type `I`, caching its bits.
+    /// If the integer is negative, this method returns an error.
+    pub fn from_integer<I: IntegerType>(mode: Mode, value: I) -> Result<Self> {
+        // Retrieve the little-endian bits of the integer.
+        let bits_le = value.to_bits_le();
+        // Ensure the integer is non-negative, as a signed integer is negative iff its sign bit is set.
+        ensure!(
+            !I::is_signed() || bits_le.last() != Some(&true),
+            "Cannot initialize a scalar from the negative integer '{value}'"
+        );
+        Ok(Self::from_unsigned_bits_le(mode, &bits_le))
+    }
+
+    /// Initializes a scalar from the little-endian bits of an unsigned integer.
+    /// Note: As there are fewer bits than the scalar field data size, the scalar is less than the modulus.
+    fn from_unsigned_bits_le(mode: Mode, bits_le: &[bool]) -> Self {
+        debug_assert!(bits_le.len() < console::Scalar::<E::Network>::size_in_data_bits());
+        // Inject the bits, and construct the scalar, which caches the bits.
+        Self::from_bits_le(&bits_le.iter().map(|bit| Boolean::new(mode, *bit)).collect::<Vec<_>>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    #[test]
+    fn test_from_u64() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            Circuit::scope(format!("{mode}"), || {
+                let candidate = Scalar::<Circuit>::from_u64(mode, 5);
+                assert_eq!(console::Scalar::from_str("5scalar").unwrap(), candidate.eject_value());
+                assert!(candidate.bits_le.get().is_some());
+                match mode {
+                    Mode::Constant => assert_scope!(64, 0, 0, 0),
+                    Mode::Public => assert_scope!(0, 64, 0, 64),
+                    Mode::Private => assert_scope!(0, 0, 64, 64),
+                }
+            });
+
+            // Ensure zero is equal to the scalar zero.
+            let candidate = Scalar::<Circuit>::from_u64(mode, 0);
+            assert_eq!(Scalar::<Circuit>::zero().eject_value(), candidate.eject_value());
+            assert!(candidate.is_equal(&Scalar::zero()).eject_value());
+
+            // Ensure the maximum value matches the console scalar.
+            let expected = console::Scalar::from_str(&format!("{}scalar", u64::MAX)).unwrap();
+            assert_eq!(expected, Scalar::<Circuit>::from_u64(mode, u64::MAX).eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_from_integer() -> Result<()> {
+        // Ensure unsigned and non-negative signed integers match the console scalar.
+        let expected = console::Scalar::from_str("5scalar")?;
+        assert_eq!(expected, Scalar::<Circuit>::from_integer(Mode::Private, 5u8)?.eject_value());
+        assert_eq!(expected, Scalar::<Circuit>::from_integer(Mode::Private, 5i32)?.eject_value());
+        let expected = console::Scalar::from_str(&format!("{}scalar", u128::MAX))?;
+        assert_eq!(expected, Scalar::<Circuit>::from_integer(Mode::Public, u128::MAX)?.eject_value());
+        let expected = console::Scalar::from_str(&format!("{}scalar", i64::MAX))?;
+        assert_eq!(expected, Scalar::<Circuit>::from_integer(Mode::Constant, i64::MAX)?.eject_value());
+        assert!(Circuit::is_satisfied());
+
+        // Ensure a negative integer fails.
+        assert!(Scalar::<Circuit>::from_integer(Mode::Private, -1i8).is_err());
+        assert!(Scalar::<Circuit>::from_integer(Mode::Private, i128::MIN).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+#[cfg(console)]
+impl<E: Environment> Scalar<E> {
+    /// Ejects the scalar circuit as a console scalar, reconstructing it from its bits without data-dependent branches.
+    /// This is intended for handling secret scalars on the host, and is not an in-circuit operation.
+    pub fn eject_value_ct(&self) -> console::Scalar<E::Network> {
+        // Retrieve the bits, using the cached bits if they were already witnessed, so no constraints are added.
+        let bits_le = match self.bits_le.get() {
+            Some(bits_le) => bits_le.eject_value(),
+            None => self.field.eject_value().to_bits_le(),
+        };
+        // Pack the bits into bytes, without branching on the value of each bit.
+        let mut bytes_le = [0u8; 32];
+        for (i, bit) in bits_le.iter().take(256).enumerate() {
+            bytes_le[i / 8] |= (*bit as u8) << (i % 8);
+        }
+        match console::FromBytes::from_bytes_le(&bytes_le) {
+            Ok(scalar) => scalar,
+            Err(error) => E::halt(format!("Failed to eject scalar value: {error}")),
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_eject_value_ct() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for _ in 0..ITERATIONS {
+                let candidate = Scalar::<Circuit>::new(mode, Uniform::rand(&mut rng));
+                // Ensure the ejected value matches, without and with the cached bits.
+                assert_eq!(candidate.eject_value(), candidate.eject_value_ct());
+                Circuit::scope("EjectValueCT", || {
+                    candidate.to_bits_le();
+                    let num_constraints = Circuit::num_constraints_in_scope();
+                    assert_eq!(candidate.eject_value(), candidate.eject_value_ct());
+                    assert_eq!(num_constraints, Circuit::num_constraints_in_scope());
+                });
+            }
+            Circuit::reset();
+        }
+
+        // Ensure the boundary values match.
+        let one = console::Scalar::<<Circuit as Environment>::Network>::one();
+        for given in [console::Scalar::zero(), one, -one] {
+            assert_eq!(given, Scalar::<Circuit>::new(Mode::Private, given).eject_value_ct());
+        }
+    }
+}
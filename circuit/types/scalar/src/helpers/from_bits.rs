@@ -95,11 +95,54 @@ impl<E: Environment> FromBits for Scalar<E> {
     }
 }
 
+impl<E: Environment> Scalar<E> {
+    /// Initializes a new scalar field element from a list of **little-endian** bits, *without* range checks.
+    /// The given bits are cached, so that subsequent calls to `ToBits` incur no constraint costs.
+    ///
+    /// # Safety Contract
+    /// The caller must ensure the given bits are already constrained to be booleans (as `Boolean` enforces),
+    /// and that they represent a value that is strictly less than `ScalarField::MODULUS`.
+    /// This holds for bits that were obtained from `Scalar::to_bits_le`, or from a gadget that enforces the range.
+    /// Otherwise, the resulting scalar is not a canonical scalar field element, and the circuit may be unsound.
+    ///
+    /// This method will halt if `bits_le` is longer than `E::ScalarField::size_in_bits()`.
+    /// If `bits_le` is shorter, it is padded with `0`s up to scalar field size.
+    pub fn from_bits_le_unchecked(bits_le: &[Boolean<E>]) -> Self {
+        // Retrieve the scalar field size.
+        let size_in_bits = console::Scalar::<E::Network>::size_in_bits();
+
+        // Ensure the list of booleans is within the allowed size in bits.
+        if bits_le.len() > size_in_bits {
+            E::halt(format!(
+                "Attempted to initialize a scalar from {} bits, exceeding {size_in_bits} bits",
+                bits_le.len()
+            ))
+        }
+
+        // Construct the list of bits, resizing up if necessary.
+        let mut bits_le = bits_le.to_vec();
+        bits_le.resize(size_in_bits, Boolean::constant(false));
+
+        // Reconstruct the bits as a linear combination representing the original scalar as a field.
+        let mut accumulator = Field::zero();
+        let mut coefficient = Field::one();
+        for bit in &bits_le {
+            accumulator += Field::from_boolean(bit) * &coefficient;
+            coefficient = coefficient.double();
+        }
+
+        // Return the scalar.
+        Scalar { field: accumulator, bits_le: OnceCell::with_value(bits_le) }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use snarkvm_circuit_environment::Circuit;
 
+    type CurrentNetwork = <Circuit as Environment>::Network;
+
     const ITERATIONS: u64 = 100;
 
     fn check_from_bits_le(mode: Mode, num_constants: u64, num_public: u64, num_private: u64, num_constraints: u64) {
@@ -174,6 +217,100 @@ mod tests {
         }
     }
 
+    fn check_from_bits_le_unchecked(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random element.
+            let expected = Uniform::rand(&mut rng);
+            let given_bits = Scalar::<Circuit>::new(mode, expected).to_bits_le();
+
+            Circuit::scope(&format!("{mode} {i}"), || {
+                let candidate = Scalar::<Circuit>::from_bits_le_unchecked(&given_bits);
+                assert_eq!(expected, candidate.eject_value());
+                // Ensure the bits are cached, and no constraints are introduced.
+                assert_eq!(given_bits.eject_value(), candidate.to_bits_le().eject_value());
+                assert_scope!(0, 0, 0, 0);
+            });
+
+            // Ensure a shorter list of bits is padded with zeros.
+            let num_bits = given_bits.len() - (i as usize % 8);
+            let candidate = Scalar::<Circuit>::from_bits_le_unchecked(&given_bits[..num_bits]);
+            let expected = Scalar::<Circuit>::from_bits_le(&given_bits[..num_bits]);
+            assert_eq!(expected.eject_value(), candidate.eject_value());
+            assert_eq!(given_bits.len(), candidate.bits_le.get().unwrap().len());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_from_bits_le_unchecked_constant() {
+        check_from_bits_le_unchecked(Mode::Constant);
+    }
+
+    #[test]
+    fn test_from_bits_le_unchecked_public() {
+        check_from_bits_le_unchecked(Mode::Public);
+    }
+
+    #[test]
+    fn test_from_bits_le_unchecked_private() {
+        check_from_bits_le_unchecked(Mode::Private);
+    }
+
+    #[test]
+    fn test_from_bits_le_unchecked_fails_on_excess_bits() {
+        let bits =
+            vec![Boolean::<Circuit>::new(Mode::Private, false); console::Scalar::<CurrentNetwork>::size_in_bits() + 1];
+        let result = std::panic::catch_unwind(|| Scalar::<Circuit>::from_bits_le_unchecked(&bits));
+        assert!(result.is_err());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_from_bits_le_rejects_excess_nonzero_bits() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Public, Mode::Private] {
+            // Sample a random element, and append a nonzero excess bit.
+            let expected: console::Scalar<CurrentNetwork> = Uniform::rand(&mut rng);
+            let mut bits = Scalar::<Circuit>::new(mode, expected).to_bits_le();
+            bits.push(Boolean::new(mode, true));
+
+            // Ensure the circuit is not satisfied.
+            let _candidate = Scalar::<Circuit>::from_bits_le(&bits);
+            assert!(!Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_from_bits_le_rejects_modulus() {
+        // Retrieve the bits of `MODULUS - 1`, which is the largest scalar field element.
+        let size_in_bits = console::Scalar::<CurrentNetwork>::size_in_bits();
+        let mut modulus_minus_one_bits = (-<Circuit as Environment>::ScalarField::one()).to_bits_le();
+        modulus_minus_one_bits.truncate(size_in_bits);
+        // As the modulus is odd, the bits of `MODULUS` are the bits of `MODULUS - 1` with the lowest bit set.
+        assert!(!modulus_minus_one_bits[0]);
+        let mut modulus_bits = modulus_minus_one_bits.clone();
+        modulus_bits[0] = true;
+
+        for mode in [Mode::Public, Mode::Private] {
+            // Ensure `MODULUS - 1` is accepted.
+            let bits = modulus_minus_one_bits.iter().map(|bit| Boolean::<Circuit>::new(mode, *bit)).collect::<Vec<_>>();
+            let _candidate = Scalar::<Circuit>::from_bits_le(&bits);
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+
+            // Ensure `MODULUS` is rejected.
+            let _candidate = Scalar::<Circuit>::from_bits_le(
+                &modulus_bits.iter().map(|bit| Boolean::new(mode, *bit)).collect::<Vec<_>>(),
+            );
+            assert!(!Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
     #[test]
     fn test_from_bits_le_constant() {
         check_from_bits_le(Mode::Constant, 0, 0, 0, 0);
@@ -0,0 +1,110 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Scalar<E> {
+    /// Initializes a scalar from a base field element, enforcing the value is less than the scalar field modulus.
+    /// The bit decomposition is witnessed once, and is reused by subsequent calls to `ToBits`.
+    pub fn from_field(field: Field<E>) -> Self {
+        // Ensure a constant is a valid scalar.
+        if field.is_constant() {
+            if let Err(error) = console::Scalar::<E::Network>::from_bits_le(&field.eject_value().to_bits_le()) {
+                E::halt(format!("Failed to initialize a scalar from a field element: {error}"))
+            }
+        }
+        let scalar = Self { field, bits_le: OnceCell::new() };
+        // Witness the bit decomposition, which enforces the value is less than the scalar field modulus.
+        scalar.to_bits_le();
+        scalar
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    /// Returns the scalar field modulus, as a base field element.
+    fn scalar_modulus() -> console::Field<<Circuit as Environment>::Network> {
+        console::FromBits::from_bits_le(&<Circuit as Environment>::ScalarField::modulus().to_bits_le()).unwrap()
+    }
+
+    fn check_from_field(mode: Mode, num_constants: u64, num_public: u64, num_private: u64, num_constraints: u64) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random element.
+            let expected: console::Scalar<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+            let field = Field::<Circuit>::new(mode, console::ToField::to_field(&expected).unwrap());
+
+            Circuit::scope(&format!("{mode} {i}"), || {
+                let candidate = Scalar::<Circuit>::from_field(field);
+                assert_eq!(expected, candidate.eject_value());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+
+                // Ensure the bit decomposition is reused.
+                let expected_bits = expected.to_bits_le();
+                assert_eq!(expected_bits, candidate.to_bits_le().eject_value());
+                assert_scope!(num_constants, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+    }
+
+    fn check_from_field_fails(mode: Mode, value: console::Field<<Circuit as Environment>::Network>) {
+        let field = Field::<Circuit>::new(mode, value);
+        match mode.is_constant() {
+            true => assert!(std::panic::catch_unwind(|| Scalar::<Circuit>::from_field(field)).is_err()),
+            false => {
+                Circuit::scope(format!("{mode}"), || {
+                    let _candidate = Scalar::<Circuit>::from_field(field);
+                    assert!(!Circuit::is_satisfied_in_scope());
+                });
+            }
+        }
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_from_field_constant() {
+        check_from_field(Mode::Constant, 251, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_from_field_public() {
+        check_from_field(Mode::Public, 0, 0, 501, 503);
+    }
+
+    #[test]
+    fn test_from_field_private() {
+        check_from_field(Mode::Private, 0, 0, 501, 503);
+    }
+
+    #[test]
+    fn test_from_field_fails() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Ensure the scalar field modulus is rejected.
+            check_from_field_fails(mode, scalar_modulus());
+            // Ensure a value greater than the scalar field modulus is rejected.
+            check_from_field_fails(mode, scalar_modulus() + console::Field::one());
+            // Ensure a value that exceeds the scalar field size in bits is rejected.
+            check_from_field_fails(mode, -console::Field::one());
+        }
+    }
+}
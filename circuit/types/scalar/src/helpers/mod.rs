@@ -16,9 +16,13 @@
 
 use super::*;
 
+pub mod eject_ct;
 pub mod from_bits;
+pub mod from_field;
 pub mod one;
+pub mod product;
 pub mod to_bits;
 pub mod to_field;
 pub mod to_fields;
 pub mod zero;
+pub mod zeroizing;
@@ -20,7 +20,10 @@ impl<E: Environment> One for Scalar<E> {
     type Boolean = Boolean<E>;
 
     fn one() -> Self {
-        Self::constant(console::Scalar::one())
+        // Pre-populate the bits, so that calls to `ToBits` are free.
+        let mut bits_le = vec![Boolean::constant(false); console::Scalar::<E::Network>::size_in_bits()];
+        bits_le[0] = Boolean::constant(true);
+        Self { field: Self::constant(console::Scalar::one()).field, bits_le: OnceCell::with_value(bits_le) }
     }
 
     fn is_one(&self) -> Self::Boolean {
@@ -45,6 +48,18 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_one_bits_are_cached() {
+        let expected = console::Scalar::<<Circuit as Environment>::Network>::one().to_bits_le();
+
+        Circuit::scope("One", || {
+            let candidate = Scalar::<Circuit>::one();
+            assert!(candidate.bits_le.get().is_some());
+            assert_eq!(expected, candidate.to_bits_le().eject_value());
+            assert_scope!(1, 0, 0, 0);
+        });
+    }
+
     #[test]
     fn test_is_one() {
         let candidate = Scalar::<Circuit>::one();
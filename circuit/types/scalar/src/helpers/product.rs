@@ -0,0 +1,148 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The number of bits in each limb of a scalar, when checking the product of two scalars.
+const LIMB_BITS: usize = 84;
+/// The number of bits in each (offset) carry of the limb products.
+const CARRY_BITS: usize = 89;
+
+impl<E: Environment> Scalar<E> {
+    /// Returns the product of `self` and `other` in the scalar field.
+    ///
+    /// This method witnesses the product `r` and the quotient `q`, and enforces `self * other == q * n + r`
+    /// over the integers, where `n` is the scalar field modulus.
+    pub(crate) fn mul_mod(&self, other: &Scalar<E>) -> Scalar<E> {
+        // If both operands are constant, compute the product as a constant.
+        if self.is_constant() && other.is_constant() {
+            return Scalar::constant(self.eject_value() * other.eject_value());
+        }
+
+        // Witness the product.
+        let product: Scalar<E> = witness!(|self, other| self * other);
+        // Enforce the product over the integers.
+        self.assert_mul_mod(other, &product);
+        product
+    }
+
+    /// Ensures `self * other == remainder (mod n)`, where `n` is the scalar field modulus.
+    ///
+    /// This method witnesses the quotient `q`, and enforces `self * other == q * n + remainder` over the integers,
+    /// by decomposing each operand into limbs, and checking each limb of the difference in the base field.
+    pub(crate) fn assert_mul_mod(&self, other: &Scalar<E>, remainder: &Scalar<E>) {
+        // Retrieve the bits of the scalar field modulus `n`.
+        let modulus_bits = E::ScalarField::modulus().to_bits_le();
+        let modulus_bits = &modulus_bits[..console::Scalar::<E::Network>::size_in_bits()];
+        let modulus: console::Field<E::Network> = from_bits_or_halt::<E, _>(modulus_bits);
+
+        // Witness the quotient `q`, such that `self * other == q * n + remainder` over the integers.
+        // Note: As `q` is less than the base field modulus, `q` is recovered as `(self * other - remainder) / n`
+        // in the base field. If the remainder is incorrect, the quotient is set to zero.
+        let quotient: Scalar<E> = witness!(|self, other, remainder| match self * other == remainder {
+            true => {
+                let product = to_field_or_halt::<E>(&self) * to_field_or_halt::<E>(&other);
+                let quotient = (product - to_field_or_halt::<E>(&remainder)) / modulus;
+                from_bits_or_halt::<E, _>(&quotient.to_bits_le())
+            }
+            false => console::Scalar::zero(),
+        });
+
+        // Decompose each operand into limbs.
+        // Note: Each call to `to_bits_le` ensures the bits are the unique representation of a scalar.
+        let [a, b, q, r] = [self, other, &quotient, remainder].map(|scalar| {
+            let bits_le = scalar.to_bits_le();
+            bits_le.chunks(LIMB_BITS).map(Field::from_bits_le).collect::<Vec<_>>()
+        });
+        let n = modulus_bits
+            .chunks(LIMB_BITS)
+            .map(|chunk| Field::constant(from_bits_or_halt::<E, _>(chunk)))
+            .collect::<Vec<_>>();
+
+        // Initialize the limb base `2^LIMB_BITS`, and the carry offset `2^(CARRY_BITS - 1)`.
+        let base = Field::constant(power_of_two::<E>(LIMB_BITS));
+        let offset = Field::constant(power_of_two::<E>(CARRY_BITS - 1));
+        let base_inverse = match power_of_two::<E>(LIMB_BITS).inverse() {
+            Ok(base_inverse) => base_inverse,
+            Err(error) => E::halt(format!("Failed to invert the limb base: {error}")),
+        };
+
+        // If `self` and `other` are the same scalar, the cross terms `a_i * a_j` and `a_j * a_i` are equal,
+        // and are computed once and doubled.
+        let is_square = core::ptr::eq(self, other);
+
+        // Ensure `a * b - q * n - r == 0` over the integers, by checking each limb of the difference,
+        // and propagating the carries, which are range-checked so that no limb sum wraps around the base field.
+        let num_limbs = a.len();
+        let mut carry = Field::zero();
+        for k in 0..(2 * num_limbs - 1) {
+            // Compute the `k`-th limb of the difference, plus the carry from the previous limb.
+            let mut sum = carry;
+            for i in 0..num_limbs {
+                if let Some(j) = k.checked_sub(i).filter(|j| *j < num_limbs) {
+                    match is_square {
+                        true if i > j => (),
+                        true if i < j => sum += (&a[i] * &b[j]).double(),
+                        _ => sum += &a[i] * &b[j],
+                    }
+                    sum -= &q[i] * &n[j];
+                }
+            }
+            if let Some(r) = r.get(k) {
+                sum -= r;
+            }
+
+            // If this is the most significant limb, ensure the remaining difference is zero.
+            if k == 2 * num_limbs - 2 {
+                E::assert_eq(&sum, E::zero());
+                break;
+            }
+
+            // Witness the offset carry, and ensure it is within `[0, 2^CARRY_BITS)`.
+            let offset_carry: Field<E> = witness!(|sum, offset| sum * base_inverse + offset);
+            offset_carry.to_lower_bits_le(CARRY_BITS);
+            carry = offset_carry - &offset;
+
+            // Ensure the limb sum is a multiple of the base, with the carry as the quotient.
+            E::assert_eq(&sum, &carry * &base);
+        }
+    }
+}
+
+/// Returns the given scalar as a base field element.
+fn to_field_or_halt<E: Environment>(scalar: &console::Scalar<E::Network>) -> console::Field<E::Network> {
+    match console::ToField::to_field(scalar) {
+        Ok(field) => field,
+        Err(error) => E::halt(format!("Failed to convert a scalar into a base field element: {error}")),
+    }
+}
+
+/// Returns the value of the given little-endian bits.
+fn from_bits_or_halt<E: Environment, T: console::FromBits>(bits_le: &[bool]) -> T {
+    match T::from_bits_le(bits_le) {
+        Ok(value) => value,
+        Err(error) => E::halt(format!("Failed to recover a value from its bits: {error}")),
+    }
+}
+
+/// Returns `2^exponent`, as a base field element.
+fn power_of_two<E: Environment>(exponent: usize) -> console::Field<E::Network> {
+    let bits_le = (0..=exponent).map(|i| i == exponent).collect::<Vec<_>>();
+    match console::FromBits::from_bits_le(&bits_le) {
+        Ok(power) => power,
+        Err(error) => E::halt(format!("Failed to compute 2^{exponent}: {error}")),
+    }
+}
@@ -25,6 +25,24 @@ impl<E: Environment> ToField for Scalar<E> {
     }
 }
 
+impl<E: Environment> Scalar<E> {
+    /// Casts a batch of scalar field elements into base field elements.
+    ///
+    /// Note: Each scalar is already stored as a base field element, so the batch incurs no constraints,
+    /// and does not compute (or invalidate) the cached little-endian bits of any scalar.
+    pub fn batch_to_field(scalars: &[Scalar<E>]) -> Vec<Field<E>> {
+        scalars.iter().map(ToField::to_field).collect()
+    }
+}
+
+impl<E: Environment> Metrics<dyn ToFields<Field = Field<E>>> for Scalar<E> {
+    type Case = Vec<Mode>;
+
+    fn count(_case: &Self::Case) -> Count {
+        Count::is(0, 0, 0, 0)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -75,6 +93,49 @@ mod tests {
         check_to_field("Private", &expected.to_bits_le(), &candidate);
     }
 
+    #[test]
+    fn test_batch_to_field() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let scalars = (0..8).map(|_| Scalar::<Circuit>::new(mode, Uniform::rand(&mut rng))).collect::<Vec<_>>();
+            // Ensure the cached bits are reused, by computing them ahead of the conversion.
+            scalars.iter().for_each(|scalar| {
+                scalar.to_bits_le();
+            });
+
+            // Perform the conversion per element.
+            let (expected, num_expected_constraints) = Circuit::scope(format!("ToField {mode}"), || {
+                let expected = scalars.iter().map(|scalar| scalar.to_field()).collect::<Vec<_>>();
+                (expected, Circuit::num_constraints_in_scope())
+            });
+
+            Circuit::scope(format!("BatchToField {mode}"), || {
+                // Perform the conversion as a batch.
+                let candidate = Scalar::batch_to_field(&scalars);
+                assert_eq!(expected.len(), candidate.len());
+                for (expected, candidate) in expected.iter().zip_eq(&candidate) {
+                    assert_eq!(expected.eject_value(), candidate.eject_value());
+                    assert_eq!(expected.eject_mode(), candidate.eject_mode());
+                }
+
+                // Ensure the batch does not incur more constraints than the per-element conversion.
+                let modes = vec![mode; scalars.len()];
+                assert!(Circuit::num_constraints_in_scope() <= num_expected_constraints);
+                assert!(<Scalar<Circuit> as Metrics<dyn ToFields<Field = Field<Circuit>>>>::count(&modes).matches(
+                    Circuit::num_constants_in_scope(),
+                    Circuit::num_public_in_scope(),
+                    Circuit::num_private_in_scope(),
+                    Circuit::num_constraints_in_scope()
+                ));
+            });
+            Circuit::reset();
+        }
+
+        // Ensure an empty batch yields no field elements.
+        assert!(Scalar::<Circuit>::batch_to_field(&[]).is_empty());
+    }
+
     #[test]
     fn test_one() {
         /// Checks that the `1` scalar field element, when converted to a base field, is well-formed.
@@ -20,7 +20,9 @@ impl<E: Environment> Zero for Scalar<E> {
     type Boolean = Boolean<E>;
 
     fn zero() -> Self {
-        Self::constant(console::Scalar::zero())
+        // Pre-populate the bits, so that calls to `ToBits` are free.
+        let bits_le = vec![Boolean::constant(false); console::Scalar::<E::Network>::size_in_bits()];
+        Self { field: Self::constant(console::Scalar::zero()).field, bits_le: OnceCell::with_value(bits_le) }
     }
 
     fn is_zero(&self) -> Self::Boolean {
@@ -45,6 +47,30 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_zero_bits_are_cached() {
+        let expected = console::Scalar::<<Circuit as Environment>::Network>::zero().to_bits_le();
+
+        Circuit::scope("Zero", || {
+            let candidate = Scalar::<Circuit>::zero();
+            assert!(candidate.bits_le.get().is_some());
+            assert_eq!(expected, candidate.to_bits_le().eject_value());
+            assert_scope!(1, 0, 0, 0);
+        });
+    }
+
+    #[test]
+    fn test_zero_is_additive_identity() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let expected = Uniform::rand(&mut rng);
+            let candidate = Scalar::<Circuit>::zero() + Scalar::<Circuit>::new(mode, expected);
+            assert_eq!(expected, candidate.eject_value());
+            Circuit::reset();
+        }
+    }
+
     #[test]
     fn test_is_zero() {
         let candidate = Scalar::<Circuit>::zero();
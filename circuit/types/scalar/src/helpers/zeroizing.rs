@@ -0,0 +1,51 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+#[cfg(console)]
+impl<E: Environment> Scalar<E> {
+    /// Ejects the scalar circuit as a console scalar, in a wrapper that zeroes the console scalar when dropped.
+    /// This is intended for handling secret scalars on the host, and does not modify the circuit variables.
+    pub fn into_console_zeroizing(self) -> zeroize::Zeroizing<console::Scalar<E::Network>> {
+        zeroize::Zeroizing::new(self.eject_value_ct())
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_into_console_zeroizing() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for _ in 0..ITERATIONS {
+                let expected = Uniform::rand(&mut rng);
+                let candidate = Scalar::<Circuit>::new(mode, expected);
+                Circuit::scope("IntoConsoleZeroizing", || {
+                    // Ensure the wrapper holds the ejected value, and no circuit variables are added.
+                    assert_eq!(expected, *candidate.clone().into_console_zeroizing());
+                    assert_scope!(0, 0, 0, 0);
+                });
+            }
+        }
+    }
+}
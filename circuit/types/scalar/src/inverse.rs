@@ -0,0 +1,139 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Inverse for Scalar<E> {
+    type Output = Result<Scalar<E>>;
+
+    /// Returns the multiplicative inverse of `self` in the scalar field.
+    ///
+    /// If `self` is a constant zero, this method returns an error.
+    /// Otherwise, this method enforces `self * self^(-1) == 1 (mod n)`, which is unsatisfied if `self` is zero.
+    fn inverse(&self) -> Self::Output {
+        // If `self` is a constant, compute the inverse as a constant.
+        if self.is_constant() {
+            return Ok(Scalar::constant(self.eject_value().inverse()?));
+        }
+
+        // Witness the inverse, which is set to zero if `self` is zero.
+        let inverse: Scalar<E> = witness!(|self| match self.inverse() {
+            Ok(inverse) => inverse,
+            _ => console::Scalar::zero(),
+        });
+
+        // Ensure `self * self^(-1) == 1 (mod n)`.
+        self.assert_mul_mod(&inverse, &Scalar::one());
+
+        Ok(inverse)
+    }
+}
+
+impl<E: Environment> Metrics<dyn Inverse<Output = Result<Scalar<E>>>> for Scalar<E> {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        match case.is_constant() {
+            true => Count::is(1, 0, 0, 0),
+            false => Count::is(6, 0, 1874, 1883),
+        }
+    }
+}
+
+impl<E: Environment> OutputMode<dyn Inverse<Output = Result<Scalar<E>>>> for Scalar<E> {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case.is_constant() {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_inverse(name: &str, expected: console::Scalar<<Circuit as Environment>::Network>, mode: Mode) {
+        let a = Scalar::<Circuit>::new(mode, expected);
+
+        Circuit::scope(name, || {
+            let candidate = a.inverse().unwrap();
+            assert_eq!(console::Scalar::one(), expected * candidate.eject_value());
+            assert_eq!(expected.inverse().unwrap(), candidate.eject_value());
+            assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+            assert!(<Scalar<Circuit> as Metrics<dyn Inverse<Output = Result<Scalar<Circuit>>>>>::count(&mode).matches(
+                Circuit::num_constants_in_scope(),
+                Circuit::num_public_in_scope(),
+                Circuit::num_private_in_scope(),
+                Circuit::num_constraints_in_scope()
+            ));
+            assert_eq!(
+                <Scalar<Circuit> as OutputMode<dyn Inverse<Output = Result<Scalar<Circuit>>>>>::output_mode(&mode),
+                candidate.eject_mode()
+            );
+        });
+        Circuit::reset();
+    }
+
+    fn run_test(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let scalar: console::Scalar<<Circuit as Environment>::Network> = Uniform::rand(&mut rng);
+            if !scalar.is_zero() {
+                check_inverse(&format!("Inverse: {mode} {i}"), scalar, mode);
+            }
+        }
+        // Check the edge cases.
+        check_inverse(&format!("Inverse: {mode} one"), console::Scalar::one(), mode);
+        check_inverse(&format!("Inverse: {mode} minus one"), -console::Scalar::one(), mode);
+    }
+
+    #[test]
+    fn test_inverse_constant() {
+        run_test(Mode::Constant);
+    }
+
+    #[test]
+    fn test_inverse_public() {
+        run_test(Mode::Public);
+    }
+
+    #[test]
+    fn test_inverse_private() {
+        run_test(Mode::Private);
+    }
+
+    #[test]
+    fn test_inverse_zero() {
+        // Ensure the inverse of a constant zero fails.
+        assert!(Scalar::<Circuit>::zero().inverse().is_err());
+
+        // Ensure the inverse of a public or private zero is unsatisfied.
+        for mode in [Mode::Public, Mode::Private] {
+            let zero = Scalar::<Circuit>::new(mode, console::Scalar::zero());
+            let candidate = zero.inverse().unwrap();
+            assert_eq!(console::Scalar::zero(), candidate.eject_value());
+            assert!(!Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+}
@@ -22,8 +22,16 @@ mod helpers;
 
 pub mod add;
 pub mod compare;
+pub mod div;
+pub mod double;
 pub mod equal;
+pub mod from_integer;
+pub mod inverse;
+pub mod min_max;
+pub mod square;
+pub mod sub;
 pub mod ternary;
+pub mod to_integer;
 
 #[cfg(test)]
 use console::{TestRng, Uniform};
@@ -33,6 +41,7 @@ use snarkvm_circuit_environment::{assert_count, assert_output_mode, assert_scope
 use snarkvm_circuit_environment::prelude::*;
 use snarkvm_circuit_types_boolean::Boolean;
 use snarkvm_circuit_types_field::Field;
+use snarkvm_circuit_types_integers::Integer;
 
 #[derive(Clone)]
 pub struct Scalar<E: Environment> {
@@ -0,0 +1,153 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> MinMax for Scalar<E> {
+    type Output = Scalar<E>;
+
+    /// Returns the lesser of `self` and `other`.
+    /// Note: If `self` and `other` are equal, this method returns `self`.
+    fn min(&self, other: &Self) -> Self::Output {
+        Scalar::ternary(&other.is_less_than(self), other, self)
+    }
+
+    /// Returns the greater of `self` and `other`.
+    /// Note: If `self` and `other` are equal, this method returns `self`.
+    fn max(&self, other: &Self) -> Self::Output {
+        Scalar::ternary(&self.is_less_than(other), other, self)
+    }
+}
+
+impl<E: Environment> Metrics<dyn MinMax<Scalar<E>, Output = Scalar<E>>> for Scalar<E> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Count::is(1, 0, 0, 0),
+            (_, _) => Count::is(0, 0, 506, 508),
+        }
+    }
+}
+
+impl<E: Environment> OutputMode<dyn MinMax<Scalar<E>, Output = Scalar<E>>> for Scalar<E> {
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Mode::Constant,
+            (_, _) => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_min_max(
+        name: &str,
+        first: console::Scalar<<Circuit as Environment>::Network>,
+        second: console::Scalar<<Circuit as Environment>::Network>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Scalar::<Circuit>::new(mode_a, first);
+        let b = Scalar::<Circuit>::new(mode_b, second);
+
+        // Check `min`.
+        Circuit::scope(format!("{name} {mode_a} {mode_b} min"), || {
+            let candidate = a.min(&b);
+            assert_eq!(std::cmp::min(first, second), candidate.eject_value());
+            assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+            assert_count!(MinMax(Scalar, Scalar) => Scalar, &(mode_a, mode_b));
+            assert_output_mode!(MinMax(Scalar, Scalar) => Scalar, &(mode_a, mode_b), candidate);
+        });
+        // Check `max`.
+        Circuit::scope(format!("{name} {mode_a} {mode_b} max"), || {
+            let candidate = a.max(&b);
+            assert_eq!(std::cmp::max(first, second), candidate.eject_value());
+            assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+            assert_count!(MinMax(Scalar, Scalar) => Scalar, &(mode_a, mode_b));
+            assert_output_mode!(MinMax(Scalar, Scalar) => Scalar, &(mode_a, mode_b), candidate);
+        });
+        Circuit::reset();
+    }
+
+    fn run_test(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+            check_min_max(&format!("MinMax: {i}"), first, second, mode_a, mode_b);
+        }
+
+        // Check the edge cases.
+        let (zero, one) = (console::Scalar::zero(), console::Scalar::one());
+        check_min_max("MinMax: zero and one", zero, one, mode_a, mode_b);
+        check_min_max("MinMax: minus one and zero", -one, zero, mode_a, mode_b);
+        check_min_max("MinMax: equal", one, one, mode_a, mode_b);
+    }
+
+    #[test]
+    fn test_constant_constant() {
+        run_test(Mode::Constant, Mode::Constant);
+    }
+
+    #[test]
+    fn test_constant_public() {
+        run_test(Mode::Constant, Mode::Public);
+    }
+
+    #[test]
+    fn test_constant_private() {
+        run_test(Mode::Constant, Mode::Private);
+    }
+
+    #[test]
+    fn test_public_constant() {
+        run_test(Mode::Public, Mode::Constant);
+    }
+
+    #[test]
+    fn test_public_public() {
+        run_test(Mode::Public, Mode::Public);
+    }
+
+    #[test]
+    fn test_public_private() {
+        run_test(Mode::Public, Mode::Private);
+    }
+
+    #[test]
+    fn test_private_constant() {
+        run_test(Mode::Private, Mode::Constant);
+    }
+
+    #[test]
+    fn test_private_public() {
+        run_test(Mode::Private, Mode::Public);
+    }
+
+    #[test]
+    fn test_private_private() {
+        run_test(Mode::Private, Mode::Private);
+    }
+}
@@ -0,0 +1,115 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Square for Scalar<E> {
+    type Output = Scalar<E>;
+
+    /// Returns `self * self` in the scalar field.
+    ///
+    /// This is cheaper than multiplying two distinct scalars, as the bits of `self` are decomposed once,
+    /// and each cross term of the limb products is computed once.
+    fn square(&self) -> Self::Output {
+        self.mul_mod(self)
+    }
+}
+
+impl<E: Environment> Metrics<dyn Square<Output = Scalar<E>>> for Scalar<E> {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        match case.is_constant() {
+            true => Count::is(1, 0, 0, 0),
+            false => Count::is(5, 0, 1370, 1377),
+        }
+    }
+}
+
+impl<E: Environment> OutputMode<dyn Square<Output = Scalar<E>>> for Scalar<E> {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case.is_constant() {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_square(name: &str, expected: console::Scalar<<Circuit as Environment>::Network>, mode: Mode) {
+        let a = Scalar::<Circuit>::new(mode, expected);
+        let b = Scalar::<Circuit>::new(mode, expected);
+
+        // Multiply two distinct scalars with the same value.
+        let num_mul_mod_constraints = Circuit::scope(format!("MulMod: {name}"), || {
+            let candidate = a.mul_mod(&b);
+            assert_eq!(expected * expected, candidate.eject_value());
+            assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+            Circuit::num_constraints_in_scope()
+        });
+
+        Circuit::scope(name, || {
+            let candidate = a.square();
+            assert_eq!(a.eject_value() * a.eject_value(), candidate.eject_value());
+            assert_eq!(expected.square(), candidate.eject_value());
+            assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+            assert_count!(Square(Scalar) => Scalar, &mode);
+            assert_output_mode!(Square(Scalar) => Scalar, &mode, candidate);
+
+            // Ensure squaring is cheaper than multiplying two distinct scalars.
+            match mode.is_constant() {
+                true => assert_eq!(0, Circuit::num_constraints_in_scope()),
+                false => assert!(Circuit::num_constraints_in_scope() < num_mul_mod_constraints),
+            }
+        });
+        Circuit::reset();
+    }
+
+    fn run_test(mode: Mode) {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            check_square(&format!("Square: {mode} {i}"), Uniform::rand(&mut rng), mode);
+        }
+        // Check the edge cases.
+        check_square(&format!("Square: {mode} zero"), console::Scalar::zero(), mode);
+        check_square(&format!("Square: {mode} one"), console::Scalar::one(), mode);
+        check_square(&format!("Square: {mode} minus one"), -console::Scalar::one(), mode);
+    }
+
+    #[test]
+    fn test_square_constant() {
+        run_test(Mode::Constant);
+    }
+
+    #[test]
+    fn test_square_public() {
+        run_test(Mode::Public);
+    }
+
+    #[test]
+    fn test_square_private() {
+        run_test(Mode::Private);
+    }
+}
@@ -0,0 +1,136 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::add::scalar_modulus;
+
+impl<E: Environment> Scalar<E> {
+    /// Returns the wrapping difference of `self` and `other`, and a flag that is `true` iff `self < other`,
+    /// i.e. the subtraction borrowed from (wrapped around) the scalar field modulus.
+    pub fn checked_sub(&self, other: &Scalar<E>) -> (Scalar<E>, Boolean<E>) {
+        // Determine the variable mode.
+        if self.is_constant() && other.is_constant() {
+            // Compute the borrow and the wrapping difference as constants.
+            let borrow = Boolean::constant(self.eject_value() < other.eject_value());
+            let difference = witness!(|self, other| self - other);
+            (difference, borrow)
+        } else {
+            // Determine the borrow, which is set if `self` is less than `other`.
+            let borrow = self.is_less_than(other);
+
+            // Compute the difference on the base field, adding the modulus back if the borrow is set.
+            // Note: This is safe as the base field is larger than the scalar field.
+            let difference = self.to_field() - other.to_field() + Field::from_boolean(&borrow) * scalar_modulus::<E>();
+
+            // Return the wrapping difference and the borrow.
+            // Note: This enforces the wrapping difference is less than the modulus.
+            (Scalar::from_field(difference), borrow)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    type CurrentNetwork = <Circuit as Environment>::Network;
+
+    const ITERATIONS: u64 = 64;
+
+    fn check_checked_sub(
+        first: console::Scalar<CurrentNetwork>,
+        second: console::Scalar<CurrentNetwork>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Scalar::<Circuit>::new(mode_a, first);
+        let b = Scalar::<Circuit>::new(mode_b, second);
+
+        Circuit::scope(format!("({first} - {second})"), || {
+            let (difference, borrow) = a.checked_sub(&b);
+            assert_eq!(first - second, difference.eject_value());
+            assert_eq!(first < second, borrow.eject_value());
+            assert_eq!(mode_a.is_constant() && mode_b.is_constant(), difference.is_constant());
+            assert!(Circuit::is_satisfied_in_scope());
+        });
+        Circuit::reset();
+    }
+
+    fn run_test(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        let zero = console::Scalar::<CurrentNetwork>::zero();
+        let one = console::Scalar::<CurrentNetwork>::one();
+
+        // Check the boundaries of the scalar field, with and without a borrow.
+        for (first, second) in [(zero, zero), (one, zero), (-one, one), (zero, one), (one, -one)] {
+            check_checked_sub(first, second, mode_a, mode_b);
+        }
+
+        for _ in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+            check_checked_sub(first, second, mode_a, mode_b);
+            check_checked_sub(second, first, mode_a, mode_b);
+        }
+    }
+
+    #[test]
+    fn test_checked_sub_constant_constant() {
+        run_test(Mode::Constant, Mode::Constant);
+    }
+
+    #[test]
+    fn test_checked_sub_constant_public() {
+        run_test(Mode::Constant, Mode::Public);
+    }
+
+    #[test]
+    fn test_checked_sub_constant_private() {
+        run_test(Mode::Constant, Mode::Private);
+    }
+
+    #[test]
+    fn test_checked_sub_public_constant() {
+        run_test(Mode::Public, Mode::Constant);
+    }
+
+    #[test]
+    fn test_checked_sub_public_public() {
+        run_test(Mode::Public, Mode::Public);
+    }
+
+    #[test]
+    fn test_checked_sub_public_private() {
+        run_test(Mode::Public, Mode::Private);
+    }
+
+    #[test]
+    fn test_checked_sub_private_constant() {
+        run_test(Mode::Private, Mode::Constant);
+    }
+
+    #[test]
+    fn test_checked_sub_private_public() {
+        run_test(Mode::Private, Mode::Public);
+    }
+
+    #[test]
+    fn test_checked_sub_private_private() {
+        run_test(Mode::Private, Mode::Private);
+    }
+}
@@ -22,10 +22,57 @@ impl<E: Environment> Ternary for Scalar<E> {
 
     /// Returns `first` if `condition` is `true`, otherwise returns `second`.
     fn ternary(condition: &Self::Boolean, first: &Self, second: &Self) -> Self::Output {
+        // If the condition is a constant, return the chosen branch, along with its cached bits (if any).
+        if condition.is_constant() {
+            return match condition.eject_value() {
+                true => first.clone(),
+                false => second.clone(),
+            };
+        }
+
         // Compute the ternary over the field representation (for efficiency).
         let field = Field::ternary(condition, &first.field, &second.field);
+        // If both branches have cached bits, select the bits of the chosen branch.
+        let bits_le = match (first.bits_le.get(), second.bits_le.get()) {
+            (Some(first_bits), Some(second_bits)) => OnceCell::with_value(
+                first_bits.iter().zip_eq(second_bits).map(|(a, b)| Boolean::ternary(condition, a, b)).collect(),
+            ),
+            _ => Default::default(),
+        };
         // Return the result.
-        Self { field, bits_le: Default::default() }
+        Self { field, bits_le }
+    }
+}
+
+impl<E: Environment> Metrics<dyn Ternary<Boolean = Boolean<E>, Output = Scalar<E>>> for Scalar<E> {
+    type Case = (Mode, Mode, Mode);
+
+    /// Note: This count assumes the branches do not both have cached bits,
+    /// as selecting the cached bits with a variable condition costs an additional constraint per bit.
+    fn count(case: &Self::Case) -> Count {
+        match case {
+            (Mode::Constant, _, _)
+            | (Mode::Public, Mode::Constant, Mode::Constant)
+            | (Mode::Private, Mode::Constant, Mode::Constant) => Count::is(0, 0, 0, 0),
+            _ => Count::is(0, 0, 1, 1),
+        }
+    }
+}
+
+impl<E: Environment> OutputMode<dyn Ternary<Boolean = Boolean<E>, Output = Self>> for Scalar<E> {
+    type Case = (CircuitType<Boolean<E>>, Mode, Mode);
+
+    fn output_mode(parameter: &Self::Case) -> Mode {
+        match parameter.0.mode().is_constant() {
+            true => match &parameter.0 {
+                CircuitType::Constant(constant) => match constant.eject_value() {
+                    true => parameter.1,
+                    false => parameter.2,
+                },
+                _ => E::halt("The constant condition is required to determine output mode."),
+            },
+            false => Mode::Private,
+        }
     }
 }
 
@@ -59,6 +106,12 @@ mod tests {
             let candidate = Scalar::ternary(&condition, &a, &b);
             assert_eq!(expected, candidate.eject_value(), "{case}");
             assert_scope!(num_constants, num_public, num_private, num_constraints);
+            assert_count!(Ternary(Boolean, Scalar, Scalar) => Scalar, &(mode_condition, mode_a, mode_b));
+            // Note: If both branches are the same constant, the linear combination reduces to a constant,
+            // and its ejected mode is not determined by the condition.
+            if !(mode_a.is_constant() && mode_b.is_constant() && first == second) {
+                assert_output_mode!(Ternary(Boolean, Scalar, Scalar) => Scalar, &(CircuitType::from(&condition), mode_a, mode_b), candidate);
+            }
 
             // Check that `candidate` has a valid mode.
             candidate.eject_mode()
@@ -117,6 +170,63 @@ mod tests {
         check_ternary("false ? one : one", false, one, one);
     }
 
+    #[test]
+    fn test_ternary_carries_bits_with_constant_condition() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+            let a = Scalar::<Circuit>::new(mode, first);
+            let b = Scalar::<Circuit>::new(mode, second);
+            // Cache the bits of both branches.
+            let a_bits = a.to_bits_le().eject_value();
+            let b_bits = b.to_bits_le().eject_value();
+
+            for (flag, expected, expected_bits) in [(true, first, &a_bits), (false, second, &b_bits)] {
+                Circuit::scope(format!("{flag} ? {mode} : {mode}"), || {
+                    let candidate = Scalar::ternary(&Boolean::constant(flag), &a, &b);
+                    assert_eq!(expected, candidate.eject_value());
+                    // Ensure the bits of the chosen branch are carried over, without any constraints.
+                    assert_eq!(*expected_bits, candidate.bits_le.get().unwrap().eject_value());
+                    assert_eq!(*expected_bits, candidate.to_bits_le().eject_value());
+                    assert_scope!(0, 0, 0, 0);
+                });
+            }
+
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_ternary_carries_bits_with_variable_condition() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+            let a = Scalar::<Circuit>::new(mode, first);
+            let b = Scalar::<Circuit>::new(mode, second);
+
+            // Ensure the bits are not carried over if only one branch has cached bits.
+            let a_bits = a.to_bits_le().eject_value();
+            let candidate = Scalar::ternary(&Boolean::new(Mode::Private, true), &a, &b);
+            assert_eq!(first, candidate.eject_value());
+            assert!(candidate.bits_le.get().is_none());
+
+            // Ensure the bits of the chosen branch are carried over if both branches have cached bits.
+            let b_bits = b.to_bits_le().eject_value();
+            for (flag, expected, expected_bits) in [(true, first, &a_bits), (false, second, &b_bits)] {
+                let candidate = Scalar::ternary(&Boolean::new(Mode::Private, flag), &a, &b);
+                assert_eq!(expected, candidate.eject_value());
+                assert_eq!(*expected_bits, candidate.bits_le.get().unwrap().eject_value());
+                assert_eq!(*expected_bits, candidate.to_bits_le().eject_value());
+            }
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
     #[test]
     fn test_if_constant_then_constant_else_constant() {
         run_test(Mode::Constant, Mode::Constant, Mode::Constant, 0, 0, 0, 0);
@@ -0,0 +1,180 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> ToInteger<I> for Scalar<E> {
+    type Output = Result<Integer<E, I>>;
+
+    /// Casts the scalar into an integer of type `I`, reusing the cached bits of the scalar.
+    ///
+    /// If the scalar is a constant that does not fit in `I`, this method returns an error.
+    /// Otherwise, this method enforces that the scalar fits in `I`, which is unsatisfied if it does not.
+    /// Note: As a scalar is non-negative, a signed integer type additionally requires the sign bit to be zero.
+    fn to_integer(&self) -> Self::Output {
+        // Retrieve the little-endian bits of the scalar.
+        let bits_le = self.to_bits_le();
+
+        // Determine the number of bits that may be set, which excludes the sign bit of a signed integer.
+        let num_value_bits = match I::is_signed() {
+            true => I::BITS as usize - 1,
+            false => I::BITS as usize,
+        };
+        let (_, upper_bits) = bits_le.split_at(num_value_bits);
+
+        match self.is_constant() {
+            // If the scalar is a constant, ensure the upper bits are zero.
+            true => ensure!(
+                upper_bits.iter().all(|bit| !bit.eject_value()),
+                "Scalar '{}' does not fit in a '{}' integer",
+                self.eject_value(),
+                I::type_name()
+            ),
+            // Otherwise, enforce that the sum of the upper bits is zero.
+            // Note: As each bit is boolean, and there are fewer bits than the modulus, this ensures each bit is zero.
+            false => {
+                let sum = upper_bits.iter().fold(E::zero(), |sum, bit| sum + &**bit);
+                E::assert_eq(E::zero(), sum);
+            }
+        }
+
+        // Return the integer.
+        Ok(Integer::from_bits_le(&bits_le[..I::BITS as usize]))
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn ToInteger<I, Output = Result<Integer<E, I>>>> for Scalar<E> {
+    type Case = Mode;
+
+    fn count(case: &Self::Case) -> Count {
+        match case {
+            Mode::Constant => Count::is(251, 0, 0, 0),
+            _ => Count::is(0, 0, 501, 504),
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn ToInteger<I, Output = Result<Integer<E, I>>>> for Scalar<E> {
+    type Case = Mode;
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        match case {
+            Mode::Constant => Mode::Constant,
+            _ => Mode::Private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_to_integer<I: IntegerType>(mode: Mode, rng: &mut TestRng) -> Result<()> {
+        for i in 0..ITERATIONS {
+            // Sample random integer bits, clearing the sign bit of a signed integer.
+            let mut bits_le = <I as Uniform>::rand(rng).to_bits_le();
+            if I::is_signed() {
+                bits_le[I::BITS as usize - 1] = false;
+            }
+            let expected = console::Scalar::<<Circuit as Environment>::Network>::from_bits_le(&bits_le)?;
+            let scalar = Scalar::<Circuit>::new(mode, expected);
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let candidate = ToInteger::<I>::to_integer(&scalar)?;
+                assert_eq!(bits_le, candidate.eject_value().to_bits_le());
+
+                // Ensure the metrics and output mode match.
+                let count =
+                    <Scalar<Circuit> as Metrics<dyn ToInteger<I, Output = Result<Integer<Circuit, I>>>>>::count(&mode);
+                assert!(count.matches(
+                    Circuit::num_constants_in_scope(),
+                    Circuit::num_public_in_scope(),
+                    Circuit::num_private_in_scope(),
+                    Circuit::num_constraints_in_scope()
+                ));
+                let output_mode = <Scalar<Circuit> as OutputMode<
+                    dyn ToInteger<I, Output = Result<Integer<Circuit, I>>>,
+                >>::output_mode(&mode);
+                assert_eq!(output_mode, candidate.eject_mode());
+                Ok::<_, Error>(())
+            })?;
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_integer() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            check_to_integer::<u8>(mode, &mut rng)?;
+            check_to_integer::<u64>(mode, &mut rng)?;
+            check_to_integer::<i64>(mode, &mut rng)?;
+            check_to_integer::<u128>(mode, &mut rng)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_integer_u64() -> Result<()> {
+        let expected = console::Scalar::<<Circuit as Environment>::Network>::from_str("123456789scalar")?;
+        let scalar = Scalar::<Circuit>::new(Mode::Private, expected);
+
+        // Ensure a scalar that fits is cast into a `u64`.
+        let candidate = ToInteger::<u64>::to_integer(&scalar)?;
+        assert_eq!(123456789u64, *candidate.eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_integer_fails() -> Result<()> {
+        // Initialize a scalar that exceeds `u64::MAX`.
+        let expected =
+            console::Scalar::<<Circuit as Environment>::Network>::from_str(&format!("{}scalar", 1u128 << 64))?;
+
+        // Ensure a constant scalar that is too large returns an error.
+        let scalar = Scalar::<Circuit>::new(Mode::Constant, expected);
+        assert!(ToInteger::<u64>::to_integer(&scalar).is_err());
+        assert!(ToInteger::<u128>::to_integer(&scalar).is_ok());
+        Circuit::reset();
+
+        // Ensure a private scalar that is too large fails the range check.
+        for mode in [Mode::Public, Mode::Private] {
+            let scalar = Scalar::<Circuit>::new(mode, expected);
+            ToInteger::<u64>::to_integer(&scalar)?;
+            assert!(!Circuit::is_satisfied());
+            Circuit::reset();
+        }
+
+        // Ensure a private scalar that sets the sign bit fails the range check for a signed integer.
+        let expected =
+            console::Scalar::<<Circuit as Environment>::Network>::from_str(&format!("{}scalar", 1u64 << 63))?;
+        let scalar = Scalar::<Circuit>::new(Mode::Private, expected);
+        ToInteger::<u64>::to_integer(&scalar)?;
+        assert!(Circuit::is_satisfied());
+        ToInteger::<i64>::to_integer(&scalar)?;
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Literal<N> {
+    /// Returns the number of bytes in the byte representation of this literal, including the variant.
+    pub fn size_in_bytes(&self) -> usize {
+        let size = match self {
+            Self::Address(..) => Address::<N>::size_in_bytes(),
+            Self::Boolean(..) => Boolean::<N>::size_in_bytes(),
+            Self::Field(..) => Field::<N>::size_in_bytes(),
+            Self::Group(..) => Group::<N>::size_in_bytes(),
+            Self::I8(..) => I8::<N>::size_in_bytes(),
+            Self::I16(..) => I16::<N>::size_in_bytes(),
+            Self::I32(..) => I32::<N>::size_in_bytes(),
+            Self::I64(..) => I64::<N>::size_in_bytes(),
+            Self::I128(..) => I128::<N>::size_in_bytes(),
+            Self::U8(..) => U8::<N>::size_in_bytes(),
+            Self::U16(..) => U16::<N>::size_in_bytes(),
+            Self::U32(..) => U32::<N>::size_in_bytes(),
+            Self::U64(..) => U64::<N>::size_in_bytes(),
+            Self::U128(..) => U128::<N>::size_in_bytes(),
+            Self::Scalar(..) => Scalar::<N>::size_in_bytes(),
+            // The string is prefixed with its length as a u16.
+            Self::String(string) => 2 + string.len(),
+        };
+        // The variant is written as a u16.
+        2 + size
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_size_in_bytes() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let literal_types = [
+            LiteralType::Address,
+            LiteralType::Boolean,
+            LiteralType::Field,
+            LiteralType::Group,
+            LiteralType::I8,
+            LiteralType::I16,
+            LiteralType::I32,
+            LiteralType::I64,
+            LiteralType::I128,
+            LiteralType::U8,
+            LiteralType::U16,
+            LiteralType::U32,
+            LiteralType::U64,
+            LiteralType::U128,
+            LiteralType::Scalar,
+            LiteralType::String,
+        ];
+
+        for _ in 0..ITERATIONS {
+            for literal_type in literal_types {
+                let literal = Literal::<CurrentNetwork>::sample(literal_type, &mut rng);
+                assert_eq!(literal.to_bytes_le()?.len(), literal.size_in_bytes(), "{literal}");
+            }
+        }
+        Ok(())
+    }
+}
@@ -30,7 +30,7 @@ mod record;
 pub use record::{Entry, Owner, Record};
 
 mod register;
-pub use register::Register;
+pub use register::{AliasedRegister, Register, RegisterAliases, RegisterKey};
 
 mod value;
-pub use value::Value;
+pub use value::{FlattenedLeaf, Value, ValueKind};
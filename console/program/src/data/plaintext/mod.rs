@@ -23,6 +23,7 @@ mod from_fields;
 mod num_randomizers;
 mod parse;
 mod serialize;
+mod size_in_bytes;
 mod size_in_fields;
 mod to_bits;
 mod to_fields;
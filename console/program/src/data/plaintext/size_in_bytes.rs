@@ -0,0 +1,36 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Plaintext<N> {
+    /// Returns the number of bytes in the byte representation of this plaintext.
+    pub fn size_in_bytes(&self) -> usize {
+        // The variant is written as a u8.
+        1 + match self {
+            Self::Literal(literal, ..) => literal.size_in_bytes(),
+            // The number of members is written as a u8, and each member is prefixed with its size as a u16.
+            Self::Struct(members, ..) => {
+                1 + members
+                    .iter()
+                    .map(|(identifier, plaintext)| {
+                        1 + identifier.size_in_bits() as usize / 8 + 2 + plaintext.size_in_bytes()
+                    })
+                    .sum::<usize>()
+            }
+        }
+    }
+}
@@ -31,6 +31,7 @@ mod parse_ciphertext;
 mod parse_plaintext;
 mod serial_number;
 mod serialize;
+mod size_in_bytes;
 mod tag;
 mod to_bits;
 mod to_commitment;
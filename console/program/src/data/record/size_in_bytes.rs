@@ -0,0 +1,41 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Record<N, Plaintext<N>> {
+    /// Returns the number of bytes in the byte representation of this record.
+    pub fn size_in_bytes(&self) -> usize {
+        // The owner variant is written as a u8.
+        let owner = 1 + match &self.owner {
+            Owner::Public(..) => Address::<N>::size_in_bytes(),
+            Owner::Private(plaintext) => plaintext.size_in_bytes(),
+        };
+        // The number of entries is written as a u8, and each entry is prefixed with its size as a u16.
+        let data = 1 + self
+            .data
+            .iter()
+            .map(|(identifier, entry)| {
+                let plaintext = match entry {
+                    Entry::Constant(plaintext) | Entry::Public(plaintext) | Entry::Private(plaintext) => plaintext,
+                };
+                // The entry variant is written as a u8.
+                1 + identifier.size_in_bits() as usize / 8 + 2 + 1 + plaintext.size_in_bytes()
+            })
+            .sum::<usize>();
+        owner + data + Group::<N>::size_in_bytes()
+    }
+}
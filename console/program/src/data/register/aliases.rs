@@ -0,0 +1,110 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use indexmap::IndexMap;
+
+/// A side-table of source names for register locators, used to annotate registers in diagnostics.
+/// Note: Aliases are purely informational, and are not considered when parsing registers.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RegisterAliases<N: Network> {
+    /// The mapping of register locators to source names.
+    aliases: IndexMap<u64, Identifier<N>>,
+}
+
+impl<N: Network> RegisterAliases<N> {
+    /// Initializes a new, empty set of register aliases.
+    pub fn new() -> Self {
+        Self { aliases: IndexMap::new() }
+    }
+
+    /// Inserts the source name for the given locator, returning the previous name, if one exists.
+    pub fn insert(&mut self, locator: u64, name: Identifier<N>) -> Option<Identifier<N>> {
+        self.aliases.insert(locator, name)
+    }
+
+    /// Returns the source name for the given locator, if one exists.
+    pub fn name_of(&self, locator: u64) -> Option<&Identifier<N>> {
+        self.aliases.get(&locator)
+    }
+
+    /// Returns the number of aliases.
+    pub fn len(&self) -> usize {
+        self.aliases.len()
+    }
+
+    /// Returns `true` if there are no aliases.
+    pub fn is_empty(&self) -> bool {
+        self.aliases.is_empty()
+    }
+
+    /// Returns a wrapper that displays the given register, annotated with its source name, if one exists.
+    pub const fn annotate<'a>(&'a self, register: &'a Register<N>) -> AliasedRegister<'a, N> {
+        AliasedRegister { register, aliases: self }
+    }
+}
+
+/// A register that is displayed with its source name, i.e. `r3 /* amount */`.
+#[derive(Copy, Clone, Debug)]
+pub struct AliasedRegister<'a, N: Network> {
+    /// The register.
+    register: &'a Register<N>,
+    /// The register aliases.
+    aliases: &'a RegisterAliases<N>,
+}
+
+impl<N: Network> Display for AliasedRegister<'_, N> {
+    /// Prints the register, followed by its source name (if one exists) as a comment.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.aliases.name_of(self.register.locator()) {
+            Some(name) => write!(f, "{} /* {name} */", self.register),
+            None => Display::fmt(self.register, f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_register_aliases() -> Result<()> {
+        let mut aliases = RegisterAliases::<CurrentNetwork>::new();
+        assert!(aliases.is_empty());
+        assert_eq!(None, aliases.insert(3, Identifier::from_str("amount")?));
+        assert_eq!(Some(&Identifier::from_str("amount")?), aliases.name_of(3));
+        assert_eq!(None, aliases.name_of(2));
+        assert_eq!(1, aliases.len());
+
+        // Ensure a register with an alias renders the annotated form.
+        let register = Register::<CurrentNetwork>::from_str("r3")?;
+        assert_eq!("r3 /* amount */", aliases.annotate(&register).to_string());
+        let register = Register::<CurrentNetwork>::from_str("r3.owner")?;
+        assert_eq!("r3.owner /* amount */", aliases.annotate(&register).to_string());
+
+        // Ensure a register without an alias renders plainly.
+        let register = Register::<CurrentNetwork>::from_str("r2")?;
+        assert_eq!("r2", aliases.annotate(&register).to_string());
+
+        // Ensure the annotated form is not parsed as a register.
+        assert!(Register::<CurrentNetwork>::from_str("r3 /* amount */").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,130 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A register key wraps a register, and is ordered by the register locator *and* its member identifiers.
+/// This allows distinct register members (i.e. `r0.a` and `r0.b`) to be used as distinct keys in ordered maps.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct RegisterKey<N: Network> {
+    /// The register.
+    register: Register<N>,
+    /// The member identifiers of the register, as strings (or empty, if the register is a locator).
+    /// Note: These are computed once on construction, so that comparisons do not allocate.
+    path: Box<[Box<str>]>,
+}
+
+impl<N: Network> RegisterKey<N> {
+    /// Returns the register.
+    #[inline]
+    pub const fn register(&self) -> &Register<N> {
+        &self.register
+    }
+
+    /// Returns the register, consuming the key.
+    #[inline]
+    pub fn into_register(self) -> Register<N> {
+        self.register
+    }
+}
+
+impl<N: Network> From<Register<N>> for RegisterKey<N> {
+    /// Initializes a register key from a register.
+    fn from(register: Register<N>) -> Self {
+        let path = match &register {
+            Register::Locator(..) => Box::default(),
+            Register::Member(_, path) => {
+                path.iter().map(|identifier| identifier.to_string().into_boxed_str()).collect()
+            }
+        };
+        Self { register, path }
+    }
+}
+
+impl<N: Network> Ord for RegisterKey<N> {
+    /// Ordering is determined by the register locator, then by the member identifiers (in lexicographic order).
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.register.locator().cmp(&other.register.locator()).then_with(|| self.path.cmp(&other.path)).then_with(
+            || matches!(self.register, Register::Member(..)).cmp(&matches!(other.register, Register::Member(..))),
+        )
+    }
+}
+
+impl<N: Network> PartialOrd for RegisterKey<N> {
+    /// Ordering is determined by the register locator, then by the member identifiers (in lexicographic order).
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N: Network> Debug for RegisterKey<N> {
+    /// Prints the register key as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.register, f)
+    }
+}
+
+impl<N: Network> Display for RegisterKey<N> {
+    /// Prints the register key as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.register, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    use std::collections::BTreeMap;
+
+    type CurrentNetwork = Testnet3;
+
+    fn key(register: &str) -> Result<RegisterKey<CurrentNetwork>> {
+        Ok(RegisterKey::from(Register::from_str(register)?))
+    }
+
+    #[test]
+    fn test_register_key_ord() -> Result<()> {
+        // Ensure the locator ordering is preserved.
+        assert!(key("r0")? < key("r1")?);
+        assert!(key("r0.b")? < key("r1.a")?);
+        assert!(key("r2")? > key("r1.a")?);
+
+        // Ensure the member identifiers are ordered.
+        assert!(key("r0.a")? < key("r0.b")?);
+        assert!(key("r0.a")? < key("r0.a.b")?);
+        assert!(key("r0.ab")? < key("r0.b")?);
+        assert!(key("r0")? < key("r0.a")?);
+        assert_eq!(Ordering::Equal, key("r0.a.b")?.cmp(&key("r0.a.b")?));
+
+        // Ensure the register ordering still ignores the member identifiers.
+        assert_eq!(Ordering::Equal, Register::<CurrentNetwork>::from_str("r0.a")?.cmp(&Register::from_str("r0.b")?));
+        Ok(())
+    }
+
+    #[test]
+    fn test_register_key_in_map() -> Result<()> {
+        let mut map = BTreeMap::new();
+        for register in ["r1", "r0.b", "r0.a", "r0"] {
+            map.insert(key(register)?, register);
+        }
+        // Ensure each register member is a distinct key, in sorted order.
+        assert_eq!(vec!["r0", "r0.a", "r0.b", "r1"], map.values().copied().collect::<Vec<_>>());
+        assert_eq!(Some(&"r0.b"), map.get(&key("r0.b")?));
+        Ok(())
+    }
+}
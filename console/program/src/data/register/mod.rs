@@ -14,6 +14,12 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod aliases;
+pub use aliases::{AliasedRegister, RegisterAliases};
+
+mod key;
+pub use key::RegisterKey;
+
 mod bytes;
 mod parse;
 mod serialize;
@@ -22,6 +28,10 @@ use crate::Identifier;
 use snarkvm_console_network::prelude::*;
 
 /// A register contains the location data to a value in memory.
+///
+/// Note: Equality compares the locator *and* the member identifiers, whereas ordering compares only
+/// the locator. As such, `r0.a != r0.b` while `r0.a.cmp(&r0.b) == Ordering::Equal`, and distinct
+/// members of a register collide as keys in a `BTreeMap`. Use `RegisterKey` for ordered maps instead.
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub enum Register<N: Network> {
     /// A register contains its locator in memory.
@@ -39,6 +49,47 @@ impl<N: Network> Register<N> {
             Self::Member(locator, _) => *locator,
         }
     }
+
+    /// Returns the number of member identifiers in the register access, or `0` for a locator.
+    #[inline]
+    pub fn depth(&self) -> usize {
+        match self {
+            Self::Locator(..) => 0,
+            Self::Member(_, identifiers) => identifiers.len(),
+        }
+    }
+
+    /// Returns `true` if the registers have the same locator, ignoring any member identifiers.
+    /// This is the equality that is consistent with the ordering of registers.
+    #[inline]
+    pub const fn same_locator(&self, other: &Self) -> bool {
+        self.locator() == other.locator()
+    }
+
+    /// Returns a copy of the register, with its locator shifted by the given offset.
+    /// Any member identifiers are preserved.
+    pub fn shift(&self, offset: u64) -> Result<Self> {
+        let locator = match self.locator().checked_add(offset) {
+            Some(locator) => locator,
+            None => bail!("Failed to shift register '{self}' by {offset}: the locator overflows"),
+        };
+        match self {
+            Self::Locator(..) => Ok(Self::Locator(locator)),
+            Self::Member(_, identifiers) => Ok(Self::Member(locator, identifiers.clone())),
+        }
+    }
+
+    /// Returns a copy of the register, with each member identifier matching `from` renamed to `to`.
+    /// A register without members is returned unchanged.
+    pub fn rename_member(&self, from: &Identifier<N>, to: &Identifier<N>) -> Self {
+        match self {
+            Self::Locator(..) => self.clone(),
+            Self::Member(locator, identifiers) => Self::Member(
+                *locator,
+                identifiers.iter().map(|identifier| if identifier == from { *to } else { *identifier }).collect(),
+            ),
+        }
+    }
 }
 
 impl<N: Network> Ord for Register<N> {
@@ -62,6 +113,22 @@ mod tests {
 
     type CurrentNetwork = Testnet3;
 
+    #[test]
+    fn test_register_depth() -> Result<()> {
+        assert_eq!(0, Register::<CurrentNetwork>::from_str("r0")?.depth());
+        assert_eq!(1, Register::<CurrentNetwork>::from_str("r0.owner")?.depth());
+        assert_eq!(3, Register::<CurrentNetwork>::from_str("r0.a.b.c")?.depth());
+
+        // Ensure a path at the maximum depth is accepted, and a deeper path is rejected.
+        let path = |depth: usize| (0..depth).map(|i| format!(".m{i}")).collect::<String>();
+        let register = Register::<CurrentNetwork>::from_str(&format!("r0{}", path(CurrentNetwork::MAX_DATA_DEPTH)))?;
+        assert_eq!(CurrentNetwork::MAX_DATA_DEPTH, register.depth());
+        assert!(
+            Register::<CurrentNetwork>::from_str(&format!("r0{}", path(CurrentNetwork::MAX_DATA_DEPTH + 1))).is_err()
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_register_partial_ord() -> Result<()> {
         // Register::Locator
@@ -109,6 +176,82 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_register_same_locator() -> Result<()> {
+        let a = Register::<CurrentNetwork>::from_str("r0.a")?;
+        let b = Register::<CurrentNetwork>::from_str("r0.b")?;
+
+        // Ensure equality distinguishes the members, while the ordering does not.
+        assert_ne!(a, b);
+        assert_eq!(Ordering::Equal, a.cmp(&b));
+        assert!(a.same_locator(&b));
+        assert!(a.same_locator(&Register::from_str("r0")?));
+        assert!(!a.same_locator(&Register::from_str("r1.a")?));
+
+        // Ensure distinct members collide as keys in an ordered map, but not as register keys.
+        let mut map = std::collections::BTreeMap::new();
+        map.insert(a.clone(), 0);
+        map.insert(b.clone(), 1);
+        assert_eq!(1, map.len());
+        assert_eq!(Some(&1), map.get(&a));
+
+        let mut map = std::collections::BTreeMap::new();
+        map.insert(RegisterKey::from(a), 0);
+        map.insert(RegisterKey::from(b), 1);
+        assert_eq!(2, map.len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_register_shift() -> Result<()> {
+        // Register::Locator
+        assert_eq!(Register::<CurrentNetwork>::from_str("r5")?, Register::from_str("r0")?.shift(5)?);
+        assert_eq!(Register::<CurrentNetwork>::from_str("r3")?, Register::from_str("r3")?.shift(0)?);
+
+        // Register::Member
+        assert_eq!(Register::<CurrentNetwork>::from_str("r7.owner")?, Register::from_str("r2.owner")?.shift(5)?);
+        assert_eq!(Register::<CurrentNetwork>::from_str("r9.a.b.c")?, Register::from_str("r1.a.b.c")?.shift(8)?);
+
+        // Ensure the locator may be shifted up to `u64::MAX`.
+        let max = Register::<CurrentNetwork>::Locator(u64::MAX);
+        assert_eq!(max, Register::Locator(u64::MAX - 1).shift(1)?);
+        let max_member = Register::<CurrentNetwork>::Member(u64::MAX, vec![Identifier::from_str("owner")?]);
+        assert_eq!(max_member, Register::Member(0, vec![Identifier::from_str("owner")?]).shift(u64::MAX)?);
+
+        // Ensure the locator cannot overflow.
+        assert!(max.shift(1).is_err());
+        assert!(max_member.shift(1).is_err());
+        assert!(Register::<CurrentNetwork>::Locator(1).shift(u64::MAX).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_register_rename_member() -> Result<()> {
+        let amount = Identifier::from_str("amount")?;
+        let value = Identifier::from_str("value")?;
+
+        // Ensure a matching member is renamed.
+        let register = Register::<CurrentNetwork>::from_str("r3.amount")?;
+        assert_eq!(Register::from_str("r3.value")?, register.rename_member(&amount, &value));
+
+        // Ensure a non-matching member is unchanged.
+        let register = Register::<CurrentNetwork>::from_str("r3.owner")?;
+        assert_eq!(register, register.rename_member(&amount, &value));
+
+        // Ensure a locator is unchanged.
+        let register = Register::<CurrentNetwork>::from_str("r3")?;
+        assert_eq!(register, register.rename_member(&amount, &value));
+
+        // Ensure an interior member is renamed.
+        let register = Register::<CurrentNetwork>::from_str("r3.token.amount.inner")?;
+        assert_eq!(Register::from_str("r3.token.value.inner")?, register.rename_member(&amount, &value));
+
+        // Ensure every matching member is renamed.
+        let register = Register::<CurrentNetwork>::from_str("r3.amount.amount")?;
+        assert_eq!(Register::from_str("r3.value.value")?, register.rename_member(&amount, &value));
+        Ok(())
+    }
+
     #[test]
     fn test_register_eq() -> Result<()> {
         // Register::Locator
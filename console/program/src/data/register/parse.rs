@@ -19,6 +19,10 @@ use super::*;
 impl<N: Network> Parser for Register<N> {
     /// Parses a string into a register.
     /// The register is of the form `r{locator}` or `r{locator}.{identifier}`.
+    ///
+    /// Note: Each member must be a valid identifier, which begins with a letter.
+    /// As such, reserved record fields that begin with an underscore (i.e. `_nonce`) are not accessible,
+    /// and `r0._nonce` parses as `r0`, leaving `._nonce` as the remainder.
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
         // Parse the register character from the string.
@@ -44,6 +48,22 @@ impl<N: Network> Parser for Register<N> {
     }
 }
 
+impl<N: Network> Register<N> {
+    /// Parses a string into a register, skipping any leading and trailing horizontal whitespace.
+    /// Note: Use `Register::parse` to parse a register strictly, i.e. for machine-generated input.
+    #[inline]
+    pub fn parse_trimmed(string: &str) -> ParserResult<Self> {
+        // Parse the leading horizontal whitespace from the string.
+        let (string, _) = many0(one_of(" \t"))(string)?;
+        // Parse the register from the string.
+        let (string, register) = Self::parse(string)?;
+        // Parse the trailing horizontal whitespace from the string.
+        let (string, _) = many0(one_of(" \t"))(string)?;
+        // Return the register.
+        Ok((string, register))
+    }
+}
+
 impl<N: Network> FromStr for Register<N> {
     type Err = Error;
 
@@ -208,6 +228,71 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_register_parse_reserved_members() -> Result<()> {
+        // Ensure a record member is accepted.
+        let (remainder, candidate) = Register::<CurrentNetwork>::parse("r0.owner")?;
+        assert_eq!("", remainder);
+        assert_eq!(Register::Member(0, vec![Identifier::from_str("owner")?]), candidate);
+        // Ensure a member with an interior underscore is accepted.
+        assert_eq!(
+            Register::Member(0, vec![Identifier::from_str("token_amount")?]),
+            Register::<CurrentNetwork>::from_str("r0.token_amount")?
+        );
+
+        // Ensure a member beginning with an underscore is not consumed.
+        let (remainder, candidate) = Register::<CurrentNetwork>::parse("r0._nonce")?;
+        assert_eq!("._nonce", remainder);
+        assert_eq!(Register::Locator(0), candidate);
+        let (remainder, candidate) = Register::<CurrentNetwork>::parse("r1.owner._nonce")?;
+        assert_eq!("._nonce", remainder);
+        assert_eq!(Register::Member(1, vec![Identifier::from_str("owner")?]), candidate);
+
+        // Ensure a register with a member beginning with an underscore is rejected.
+        assert!(Register::<CurrentNetwork>::from_str("r0._nonce").is_err());
+        assert!(Register::<CurrentNetwork>::from_str("r0.owner._nonce").is_err());
+        assert!(Register::<CurrentNetwork>::from_str("r0._owner").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_register_parse_trimmed() -> Result<()> {
+        // Ensure surrounding horizontal whitespace is accepted by `parse_trimmed`.
+        assert_eq!(("", Register::<CurrentNetwork>::Locator(3)), Register::parse_trimmed("  r3  ")?);
+        assert_eq!(("", Register::<CurrentNetwork>::Locator(3)), Register::parse_trimmed("\tr3\t")?);
+        assert_eq!(("", Register::<CurrentNetwork>::Locator(3)), Register::parse_trimmed("r3")?);
+        assert_eq!(
+            ("", Register::<CurrentNetwork>::Member(1, vec![Identifier::from_str("owner")?])),
+            Register::parse_trimmed(" r1.owner ")?
+        );
+        // Ensure a line break is not skipped.
+        assert_eq!(("\n", Register::<CurrentNetwork>::Locator(3)), Register::parse_trimmed(" r3 \n")?);
+        assert!(Register::<CurrentNetwork>::parse_trimmed("\nr3").is_err());
+        // Ensure whitespace within the register is rejected.
+        assert_eq!(("3", Register::<CurrentNetwork>::Locator(2)), Register::parse_trimmed(" r2 3")?);
+        assert!(Register::<CurrentNetwork>::parse_trimmed("r 3").is_err());
+
+        // Ensure surrounding horizontal whitespace is rejected by `parse`.
+        assert!(Register::<CurrentNetwork>::parse("  r3  ").is_err());
+        assert_eq!(("  ", Register::<CurrentNetwork>::Locator(3)), Register::parse("r3  ")?);
+        assert!(Register::<CurrentNetwork>::from_str("  r3  ").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_register_parse_index_unsupported() -> Result<()> {
+        // Ensure an array index is not consumed, as array member types are not supported.
+        let (remainder, candidate) = Register::<CurrentNetwork>::parse("r0.data[3]")?;
+        assert_eq!("[3]", remainder);
+        assert_eq!(Register::Member(0, vec![Identifier::from_str("data")?]), candidate);
+
+        // Ensure a register with an array index is rejected.
+        assert!(Register::<CurrentNetwork>::from_str("r0.data[3]").is_err());
+        assert!(Register::<CurrentNetwork>::from_str("r0.data[x]").is_err());
+        assert!(Register::<CurrentNetwork>::from_str("r0[3]").is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_register_parser_fails() {
         assert!(Register::<CurrentNetwork>::parse("").is_err());
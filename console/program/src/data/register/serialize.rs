@@ -86,6 +86,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_serde_json_string_form() -> Result<()> {
+        // Ensure the human-readable form is the register string.
+        let locator = Register::<CurrentNetwork>::Locator(0);
+        assert_eq!("\"r0\"", serde_json::to_string(&locator)?);
+        assert_eq!(locator, serde_json::from_str("\"r0\"")?);
+
+        let member = Register::<CurrentNetwork>::Member(1, vec![Identifier::from_str("owner")?]);
+        assert_eq!("\"r1.owner\"", serde_json::to_string(&member)?);
+        assert_eq!(member, serde_json::from_str("\"r1.owner\"")?);
+
+        // Ensure an invalid register string is rejected.
+        assert!(serde_json::from_str::<Register<CurrentNetwork>>("\"x1.owner\"").is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_bincode() {
         for i in 0..1000 {
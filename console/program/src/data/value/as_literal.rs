@@ -0,0 +1,120 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// Implements a typed accessor for the given integer literal variant.
+macro_rules! impl_as_integer {
+    ($name:ident, $variant:ident, $type_:ty) => {
+        #[doc = concat!("Returns the `", stringify!($type_), "` value, if the value is a `", stringify!($type_), "` literal.")]
+        pub fn $name(&self) -> Option<$type_> {
+            match self.as_literal()? {
+                Literal::$variant(integer) => Some(**integer),
+                _ => None,
+            }
+        }
+    };
+}
+
+impl<N: Network> Value<N> {
+    impl_as_integer!(as_i8, I8, i8);
+
+    impl_as_integer!(as_i16, I16, i16);
+
+    impl_as_integer!(as_i32, I32, i32);
+
+    impl_as_integer!(as_i64, I64, i64);
+
+    impl_as_integer!(as_i128, I128, i128);
+
+    impl_as_integer!(as_u8, U8, u8);
+
+    impl_as_integer!(as_u16, U16, u16);
+
+    impl_as_integer!(as_u32, U32, u32);
+
+    impl_as_integer!(as_u64, U64, u64);
+
+    impl_as_integer!(as_u128, U128, u128);
+
+    /// Returns the literal, if the value is a plaintext literal.
+    pub fn as_literal(&self) -> Option<&Literal<N>> {
+        match self {
+            Self::Plaintext(Plaintext::Literal(literal, ..)) => Some(literal),
+            Self::Plaintext(Plaintext::Struct(..)) | Self::Record(..) => None,
+        }
+    }
+
+    /// Returns the address, if the value is an address literal.
+    pub fn as_address(&self) -> Option<Address<N>> {
+        match self.as_literal()? {
+            Literal::Address(address) => Some(*address),
+            _ => None,
+        }
+    }
+
+    /// Returns the `bool` value, if the value is a boolean literal.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self.as_literal()? {
+            Literal::Boolean(boolean) => Some(**boolean),
+            _ => None,
+        }
+    }
+
+    /// Returns the field, if the value is a field literal.
+    pub fn as_field(&self) -> Option<Field<N>> {
+        match self.as_literal()? {
+            Literal::Field(field) => Some(*field),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_as_literal() -> Result<()> {
+        // Ensure a literal is unwrapped into its typed value.
+        let value = Value::<CurrentNetwork>::from_str("100u64")?;
+        assert_eq!(Some(&Literal::from_str("100u64")?), value.as_literal());
+        assert_eq!(Some(100u64), value.as_u64());
+        assert_eq!(Some(-5i8), Value::<CurrentNetwork>::from_str("-5i8")?.as_i8());
+        assert_eq!(Some(true), Value::<CurrentNetwork>::from_str("true")?.as_bool());
+        assert_eq!(Some(Field::from_u64(7)), Value::<CurrentNetwork>::from_str("7field")?.as_field());
+
+        // Ensure a mismatched literal type is `None`.
+        assert_eq!(None, value.as_u32());
+        assert_eq!(None, value.as_i64());
+        assert_eq!(None, value.as_bool());
+
+        // Ensure a struct or record is `None`.
+        let struct_ = Value::<CurrentNetwork>::from_str("{ amount: 100u64 }")?;
+        assert_eq!(None, struct_.as_literal());
+        assert_eq!(None, struct_.as_u64());
+        let record = Value::<CurrentNetwork>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, amount: 100u64.private, _nonce: 0group.public }",
+        )?;
+        assert_eq!(None, record.as_literal());
+        assert_eq!(None, record.as_u64());
+        assert_eq!(None, record.as_address());
+        Ok(())
+    }
+}
@@ -25,6 +25,20 @@ impl<N: Network> PartialEq for Value<N> {
     }
 }
 
+impl<N: Network> Value<N> {
+    /// Returns `true` if `self` and `other` are equal, ignoring the nonce of a record.
+    /// This compares the contents of records across re-randomizations.
+    pub fn eq_ignoring_nonce(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Plaintext(a), Self::Plaintext(b)) => a == b,
+            (Self::Record(a), Self::Record(b)) => {
+                a.owner() == b.owner() && a.data().len() == b.data().len() && a.data().iter().eq(b.data().iter())
+            }
+            (Self::Plaintext(..), _) | (Self::Record(..), _) => false,
+        }
+    }
+}
+
 impl<N: Network> Equal<Self> for Value<N> {
     type Output = Boolean<N>;
 
@@ -46,3 +60,49 @@ impl<N: Network> Equal<Self> for Value<N> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const OWNER: &str = "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah";
+
+    fn sample_record(entries: &str, nonce: &str) -> Result<Value<CurrentNetwork>> {
+        Value::from_str(&format!("{{ owner: {OWNER}.private, {entries} _nonce: {nonce}.public }}"))
+    }
+
+    #[test]
+    fn test_eq_ignoring_nonce() -> Result<()> {
+        let nonce = CurrentNetwork::g_scalar_multiply(&Scalar::one()).to_string();
+
+        // Ensure records that differ only in their nonce are equal when ignoring the nonce.
+        let a = sample_record("amount: 100u64.private, memo: 1field.public,", "0group")?;
+        let b = sample_record("amount: 100u64.private, memo: 1field.public,", &nonce)?;
+        assert!(a.eq_ignoring_nonce(&b));
+        assert_ne!(a, b);
+
+        // Ensure records that differ in their owner or entries are not equal.
+        let owner = Value::from_str(&format!(
+            "{{ owner: {OWNER}.public, amount: 100u64.private, memo: 1field.public, _nonce: 0group.public }}"
+        ))?;
+        assert!(!a.eq_ignoring_nonce(&owner));
+        for entries in [
+            "amount: 101u64.private, memo: 1field.public,",
+            "amount: 100u64.public, memo: 1field.public,",
+            "amount: 100u64.private,",
+            "memo: 1field.public, amount: 100u64.private,",
+        ] {
+            assert!(!a.eq_ignoring_nonce(&sample_record(entries, "0group")?));
+        }
+
+        // Ensure plaintext values are compared as usual.
+        let plaintext = Value::<CurrentNetwork>::from_str("100u64")?;
+        assert!(plaintext.eq_ignoring_nonce(&Value::from_str("100u64")?));
+        assert!(!plaintext.eq_ignoring_nonce(&Value::from_str("101u64")?));
+        assert!(!plaintext.eq_ignoring_nonce(&a));
+        Ok(())
+    }
+}
@@ -0,0 +1,134 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{visit::visit_plaintext, *};
+use crate::{EntryMode, Owner};
+
+/// A flattened leaf of a value, consisting of its dotted member path, literal, and entry mode (for records).
+pub type FlattenedLeaf<N> = (String, Literal<N>, Option<EntryMode>);
+
+impl<N: Network> Value<N> {
+    /// Returns the dotted member path and literal of each leaf in the value, in order.
+    /// For a record, the leaves of the owner and entries are included, and the nonce is skipped.
+    pub fn flatten(&self) -> Result<Vec<(String, Literal<N>)>> {
+        let mut rows = Vec::new();
+        self.visit_leaves(|path, literal| rows.push((join_path(path), literal.clone())))?;
+        Ok(rows)
+    }
+
+    /// Returns the dotted member path, literal, and entry mode of each leaf in the value, in order.
+    /// For a record, the leaves of the owner and entries are included with their mode, and the nonce is skipped.
+    /// For a plaintext, the mode of each leaf is `None`.
+    pub fn flatten_with_modes(&self) -> Result<Vec<FlattenedLeaf<N>>> {
+        let mut rows = Vec::new();
+        match self {
+            Self::Plaintext(..) => {
+                self.visit_leaves(|path, literal| rows.push((join_path(path), literal.clone(), None)))?;
+            }
+            Self::Record(record) => {
+                let mut path = vec![Identifier::from_str("owner")?];
+                // Flatten the owner.
+                match record.owner() {
+                    Owner::Public(address) => {
+                        rows.push((join_path(&path), Literal::Address(*address), Some(EntryMode::Public)))
+                    }
+                    Owner::Private(plaintext) => visit_plaintext(plaintext, &mut path, &mut |path, literal| {
+                        rows.push((join_path(path), literal.clone(), Some(EntryMode::Private)))
+                    }),
+                }
+                // Flatten the entries.
+                for (identifier, entry) in record.data() {
+                    let (plaintext, mode) = match entry {
+                        Entry::Constant(plaintext) => (plaintext, EntryMode::Constant),
+                        Entry::Public(plaintext) => (plaintext, EntryMode::Public),
+                        Entry::Private(plaintext) => (plaintext, EntryMode::Private),
+                    };
+                    let mut path = vec![*identifier];
+                    visit_plaintext(plaintext, &mut path, &mut |path, literal| {
+                        rows.push((join_path(path), literal.clone(), Some(mode)))
+                    });
+                }
+            }
+        }
+        Ok(rows)
+    }
+}
+
+/// Returns the member path as a dotted string.
+fn join_path<N: Network>(path: &[Identifier<N>]) -> String {
+    path.iter().map(|identifier| identifier.to_string()).collect::<Vec<_>>().join(".")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ADDRESS: &str = "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah";
+
+    #[test]
+    fn test_flatten_record() -> Result<()> {
+        let value = Value::<CurrentNetwork>::from_str(&format!(
+            "{{ owner: {ADDRESS}.private, token_amount: 100u64.private, token: {{ id: 5field.public, supply: 7u32.public }}, _nonce: 0group.public }}"
+        ))?;
+
+        // Ensure the record is flattened into one row per leaf, with dotted paths.
+        let expected = vec![
+            ("owner".to_string(), Literal::from_str(ADDRESS)?),
+            ("token_amount".to_string(), Literal::from_str("100u64")?),
+            ("token.id".to_string(), Literal::from_str("5field")?),
+            ("token.supply".to_string(), Literal::from_str("7u32")?),
+        ];
+        assert_eq!(expected, value.flatten()?);
+
+        // Ensure the rows with modes match, and include the visibility of each leaf.
+        let expected_modes = [EntryMode::Private, EntryMode::Private, EntryMode::Public, EntryMode::Public];
+        let candidate = value.flatten_with_modes()?;
+        assert_eq!(expected.len(), candidate.len());
+        for ((path, literal, mode), ((expected_path, expected_literal), expected_mode)) in
+            candidate.into_iter().zip(expected.into_iter().zip(expected_modes))
+        {
+            assert_eq!(expected_path, path);
+            assert_eq!(expected_literal, literal);
+            assert_eq!(Some(expected_mode), mode);
+        }
+
+        // Ensure a public owner is flattened with the public mode.
+        let value = Value::<CurrentNetwork>::from_str(&format!(
+            "{{ owner: {ADDRESS}.public, token_amount: 100u64.constant, _nonce: 0group.public }}"
+        ))?;
+        let modes = value.flatten_with_modes()?.into_iter().map(|(.., mode)| mode).collect::<Vec<_>>();
+        assert_eq!(vec![Some(EntryMode::Public), Some(EntryMode::Constant)], modes);
+        Ok(())
+    }
+
+    #[test]
+    fn test_flatten_plaintext() -> Result<()> {
+        // Ensure a literal is flattened into a single row with an empty path.
+        let value = Value::<CurrentNetwork>::from_str("1field")?;
+        assert_eq!(vec![(String::new(), Literal::from_str("1field")?)], value.flatten()?);
+
+        // Ensure nested struct members are flattened in order, without modes.
+        let value = Value::<CurrentNetwork>::from_str("{ a: 1u8, b: { c: true } }")?;
+        let expected =
+            vec![("a".to_string(), Literal::from_str("1u8")?), ("b.c".to_string(), Literal::from_str("true")?)];
+        assert_eq!(expected, value.flatten()?);
+        assert!(value.flatten_with_modes()?.iter().all(|(.., mode)| mode.is_none()));
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Value<N> {
+    /// Writes the value to the given writer, as a `u32` little-endian length followed by its bytes.
+    ///
+    /// Note: This differs from the bincode encoding of a value, which prefixes the same bytes
+    /// with a `u64` little-endian length instead.
+    pub fn write_framed<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Retrieve the bytes of the value.
+        let bytes = self.to_bytes_le().map_err(|e| error(e.to_string()))?;
+        // Ensure the length fits within a `u32`.
+        let length = u32::try_from(bytes.len()).map_err(|_| error("Failed to frame value: too many bytes"))?;
+        // Write the length, followed by the bytes.
+        length.write_le(&mut writer)?;
+        writer.write_all(&bytes)
+    }
+
+    /// Reads a value from the given reader, as a `u32` little-endian length followed by exactly that many bytes.
+    pub fn read_framed<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the length.
+        let length = u32::read_le(&mut reader)?;
+        // Read the bytes, without trusting the length for the allocation.
+        let mut bytes = Vec::new();
+        reader.take(length as u64).read_to_end(&mut bytes)?;
+        if bytes.len() != length as usize {
+            return Err(error(format!("Failed to read framed value: expected {length} bytes, found {}", bytes.len())));
+        }
+        // Read the value, and ensure it spans the entire frame.
+        let mut body = &bytes[..];
+        let value = Self::read_le(&mut body)?;
+        match body.is_empty() {
+            true => Ok(value),
+            false => Err(error(format!("Failed to read framed value: found {} trailing bytes", body.len()))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_framed() -> Result<()> {
+        let first = Value::<CurrentNetwork>::from_str("{ a: 1u8, b: { c: 2field } }")?;
+        let second = Value::<CurrentNetwork>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, amount: 100u64.public, _nonce: 0group.public }",
+        )?;
+
+        // Write the values in sequence.
+        let mut buffer = Vec::new();
+        first.write_framed(&mut buffer)?;
+        second.write_framed(&mut buffer)?;
+
+        // Read the values back in sequence.
+        let mut reader = &buffer[..];
+        assert_eq!(first, Value::read_framed(&mut reader)?);
+        assert_eq!(second, Value::read_framed(&mut reader)?);
+        assert!(reader.is_empty());
+
+        // Ensure the frame carries the same bytes as bincode, with a `u32` length instead of a `u64` length.
+        let bincode = bincode::serialize(&first)?;
+        let mut framed = Vec::new();
+        first.write_framed(&mut framed)?;
+        assert_eq!(bincode[8..], framed[4..]);
+        assert_eq!(bincode[..4], framed[..4]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_framed_fails() -> Result<()> {
+        let value = Value::<CurrentNetwork>::from_str("5u64")?;
+        let mut buffer = Vec::new();
+        value.write_framed(&mut buffer)?;
+
+        // Ensure a truncated frame is rejected.
+        assert!(Value::<CurrentNetwork>::read_framed(&buffer[..buffer.len() - 1]).is_err());
+        // Ensure a frame with trailing bytes is rejected.
+        let mut extended = buffer.clone();
+        extended.push(0);
+        extended[0] += 1;
+        assert!(Value::<CurrentNetwork>::read_framed(&extended[..]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The kind of a value, i.e. whether it is a plaintext or a record.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ValueKind {
+    /// A plaintext value.
+    Plaintext,
+    /// A record value.
+    Record,
+}
+
+impl<N: Network> Value<N> {
+    /// Returns the kind of the value.
+    pub const fn kind(&self) -> ValueKind {
+        match self {
+            Self::Plaintext(..) => ValueKind::Plaintext,
+            Self::Record(..) => ValueKind::Record,
+        }
+    }
+
+    /// Returns the kind of the value in the given bytes, by reading only the leading variant,
+    /// without decoding the remainder of the value.
+    pub fn peek_kind(bytes: &[u8]) -> Result<ValueKind> {
+        match bytes.first() {
+            Some(0) => Ok(ValueKind::Plaintext),
+            Some(1) => Ok(ValueKind::Record),
+            Some(index) => bail!("Failed to decode value variant {index}"),
+            None => bail!("Failed to decode value variant from empty bytes"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_peek_kind() -> Result<()> {
+        let plaintext = Value::<CurrentNetwork>::from_str("{ foo: 5u8, bar: 10field }")?;
+        let record = Value::<CurrentNetwork>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, _nonce: 0group.public }",
+        )?;
+
+        // Ensure the peeked kind matches the kind of the deserialized value.
+        for (value, expected) in [(plaintext, ValueKind::Plaintext), (record, ValueKind::Record)] {
+            let bytes = value.to_bytes_le()?;
+            assert_eq!(expected, Value::<CurrentNetwork>::peek_kind(&bytes)?);
+            assert_eq!(expected, Value::<CurrentNetwork>::read_le(&bytes[..])?.kind());
+        }
+
+        // Ensure invalid bytes are rejected.
+        assert!(Value::<CurrentNetwork>::peek_kind(&[]).is_err());
+        assert!(Value::<CurrentNetwork>::peek_kind(&[2]).is_err());
+        Ok(())
+    }
+}
@@ -14,15 +14,28 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod flatten;
+pub use flatten::FlattenedLeaf;
+
+mod kind;
+pub use kind::ValueKind;
+
+mod as_literal;
 mod bytes;
 mod equal;
 mod find;
+mod framed;
 mod parse;
+mod registers;
 mod serialize;
+mod size_in_bytes;
 mod to_bits;
 mod to_fields;
+mod to_hash;
+mod truncate;
+mod visit;
 
-use crate::{Entry, Identifier, Plaintext, Record};
+use crate::{Entry, Identifier, Literal, Plaintext, Record, RecordType};
 use snarkvm_console_network::Network;
 use snarkvm_console_types::prelude::*;
 
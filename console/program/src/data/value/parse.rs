@@ -37,7 +37,39 @@ impl<N: Network> FromStr for Value<N> {
                 // Return the object.
                 Ok(object)
             }
-            Err(error) => bail!("Failed to parse string. {error}"),
+            Err(error) => {
+                // Determine the furthest position in the string that the parser reached.
+                let remainder = match &error {
+                    nom::Err::Error(error) | nom::Err::Failure(error) => {
+                        error.errors.iter().map(|(remainder, _)| *remainder).min_by_key(|remainder| remainder.len())
+                    }
+                    nom::Err::Incomplete(..) => None,
+                };
+                match remainder {
+                    Some(remainder) => {
+                        let offset = string.len().saturating_sub(remainder.len());
+                        let snippet = remainder.chars().take(16).collect::<String>();
+                        bail!("Failed to parse string at byte {offset} (near \"{snippet}\"). {error}")
+                    }
+                    None => bail!("Failed to parse string. {error}"),
+                }
+            }
+        }
+    }
+}
+
+impl<N: Network> Value<N> {
+    /// Parses a string into a record value, and ensures it matches the layout of the given record type.
+    /// Note: A record with an undeclared or missing entry, or a plaintext value, is rejected.
+    pub fn from_str_strict(string: &str, record_type: &RecordType<N>) -> Result<Self> {
+        match Self::from_str(string)? {
+            Value::Record(record) => {
+                record_type.matches(&record)?;
+                Ok(Value::Record(record))
+            }
+            Value::Plaintext(..) => {
+                bail!("Expected a record of type '{}', found a plaintext value", record_type.name())
+            }
         }
     }
 }
@@ -66,6 +98,18 @@ mod tests {
 
     type CurrentNetwork = Testnet3;
 
+    #[test]
+    fn test_value_parse_error_offset() {
+        let string = "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, token_amount: 100u6.private, _nonce: 0group.public }";
+        let error = Value::<CurrentNetwork>::from_str(string).unwrap_err().to_string();
+        // Ensure the error points at the mistyped entry.
+        let offset = string.find("token_amount").unwrap();
+        assert!(
+            error.starts_with(&format!("Failed to parse string at byte {offset} (near \"token_amount: 10\")")),
+            "{error}"
+        );
+    }
+
     #[test]
     fn test_value_plaintext_parse() {
         // Prepare the plaintext string.
@@ -92,4 +136,33 @@ mod tests {
         assert!(matches!(expected, Value::Record(..)));
         assert_eq!(string, format!("{expected}"));
     }
+
+    #[test]
+    fn test_value_from_str_strict() -> Result<()> {
+        let record_type =
+            RecordType::<CurrentNetwork>::from_str("record token: owner as address.private; amount as u64.private;")?;
+        let owner = "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah";
+
+        // Ensure a conforming record is accepted.
+        let string = format!("{{ owner: {owner}.private, amount: 100u64.private, _nonce: 0group.public }}");
+        let candidate = Value::<CurrentNetwork>::from_str_strict(&string, &record_type)?;
+        assert_eq!(Value::from_str(&string)?, candidate);
+
+        // Ensure a record with an undeclared entry is rejected.
+        let string =
+            format!("{{ owner: {owner}.private, amount: 100u64.private, fee: 1u64.private, _nonce: 0group.public }}");
+        let error = Value::<CurrentNetwork>::from_str_strict(&string, &record_type).unwrap_err();
+        assert_eq!("Record 'token' contains an unexpected entry 'fee'", error.to_string());
+        assert!(Value::<CurrentNetwork>::from_str(&string).is_ok());
+
+        // Ensure a record with a missing entry is rejected.
+        let string = format!("{{ owner: {owner}.private, _nonce: 0group.public }}");
+        let error = Value::<CurrentNetwork>::from_str_strict(&string, &record_type).unwrap_err();
+        assert_eq!("Record 'token' is missing entry 'amount'", error.to_string());
+
+        // Ensure a plaintext value is rejected.
+        let error = Value::<CurrentNetwork>::from_str_strict("{ amount: 100u64 }", &record_type).unwrap_err();
+        assert_eq!("Expected a record of type 'token', found a plaintext value", error.to_string());
+        Ok(())
+    }
 }
@@ -0,0 +1,60 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::Register;
+
+impl<N: Network> Value<N> {
+    /// Returns the registers that access the top-level members of the value, stored at the given locator.
+    /// For a record, this returns a register member for the owner and for each entry, in order.
+    /// For a plaintext, this returns the register locator.
+    /// Note: As `_nonce` is not a valid identifier, the nonce of a record is not accessible as a register member.
+    pub fn record_member_registers(&self, base_locator: u64) -> Result<Vec<Register<N>>> {
+        match self {
+            Self::Plaintext(..) => Ok(vec![Register::Locator(base_locator)]),
+            Self::Record(record) => Ok(std::iter::once(Identifier::from_str("owner")?)
+                .chain(record.data().keys().copied())
+                .map(|identifier| Register::Member(base_locator, vec![identifier]))
+                .collect()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_record_member_registers() -> Result<()> {
+        // Ensure a record produces a register member for the owner and each entry.
+        let value = Value::<CurrentNetwork>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, token_amount: 100u64.private, _nonce: 0group.public }",
+        )?;
+        let candidate = value.record_member_registers(0)?.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(vec!["r0.owner", "r0.token_amount"], candidate);
+
+        // Ensure the register members use the given locator.
+        assert!(value.record_member_registers(3)?.iter().all(|register| register.locator() == 3));
+
+        // Ensure a plaintext produces its register locator.
+        let value = Value::<CurrentNetwork>::from_str("{ a: 1u8, b: 2u8 }")?;
+        assert_eq!(vec![Register::Locator(2)], value.record_member_registers(2)?);
+        Ok(())
+    }
+}
@@ -36,6 +36,74 @@ impl<'de, N: Network> Deserialize<'de> for Value<N> {
     }
 }
 
+impl<N: Network> Value<N> {
+    /// Serializes the value into string or bytes, *without* a size encoding for the bytes.
+    /// This is intended for `#[serde(serialize_with = "Value::serialize_raw")]`,
+    /// when the outer container already delimits the value.
+    pub fn serialize_raw<S: Serializer>(value: &Self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => serializer.collect_str(value),
+            false => ToBytesSerializer::serialize(value, serializer),
+        }
+    }
+
+    /// Deserializes the value from a string or bytes, *without* a size encoding for the bytes.
+    /// This is the inverse of `Value::serialize_raw`, and only reads as many bytes as the value requires.
+    pub fn deserialize_raw<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => FromStr::from_str(&String::deserialize(deserializer)?).map_err(de::Error::custom),
+            false => deserializer.deserialize_tuple(usize::MAX, RawValueVisitor(core::marker::PhantomData)),
+        }
+    }
+}
+
+/// A visitor that reads a value from a sequence of bytes, without a size encoding.
+struct RawValueVisitor<N: Network>(core::marker::PhantomData<N>);
+
+impl<'de, N: Network> de::Visitor<'de> for RawValueVisitor<N> {
+    type Value = Value<N>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a valid value")
+    }
+
+    fn visit_seq<S: de::SeqAccess<'de>>(self, mut seq: S) -> Result<Self::Value, S::Error> {
+        // Read the value, requesting each byte from the sequence as it is needed.
+        let mut reader = SeqReader { seq: &mut seq, error: None, _phantom: core::marker::PhantomData };
+        let value = Value::read_le(&mut reader);
+        match (value, reader.error) {
+            // If the sequence failed, return its error.
+            (_, Some(error)) => Err(error),
+            (Ok(value), None) => Ok(value),
+            (Err(error), None) => Err(de::Error::custom(error)),
+        }
+    }
+}
+
+/// A reader over a sequence of bytes, which retains the error of the sequence (if any).
+struct SeqReader<'a, 'de, S: de::SeqAccess<'de>> {
+    seq: &'a mut S,
+    error: Option<S::Error>,
+    _phantom: core::marker::PhantomData<&'de ()>,
+}
+
+impl<'a, 'de, S: de::SeqAccess<'de>> std::io::Read for SeqReader<'a, 'de, S> {
+    fn read(&mut self, buffer: &mut [u8]) -> std::io::Result<usize> {
+        for (i, byte) in buffer.iter_mut().enumerate() {
+            match self.seq.next_element::<u8>() {
+                Ok(Some(next)) => *byte = next,
+                // The sequence has ended.
+                Ok(None) => return Ok(i),
+                Err(error) => {
+                    self.error = Some(error);
+                    return Err(std::io::Error::new(std::io::ErrorKind::Other, "Failed to read the next byte"));
+                }
+            }
+        }
+        Ok(buffer.len())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -87,6 +155,56 @@ mod tests {
         Ok(())
     }
 
+    /// A wrapper that serializes the value with `Value::serialize_raw`.
+    struct Raw<'a>(&'a Value<CurrentNetwork>);
+
+    impl Serialize for Raw<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            Value::serialize_raw(self.0, serializer)
+        }
+    }
+
+    /// A wrapper that deserializes the value with `Value::deserialize_raw`.
+    struct RawOwned(Value<CurrentNetwork>);
+
+    impl<'de> Deserialize<'de> for RawOwned {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            Value::deserialize_raw(deserializer).map(RawOwned)
+        }
+    }
+
+    #[test]
+    fn test_raw() -> Result<()> {
+        for string in [
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah, token_amount: 100u64 }",
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, token_amount: 100u64.private, _nonce: 0group.public }",
+            "5u8",
+        ] {
+            let expected = Value::<CurrentNetwork>::from_str(string)?;
+
+            // Ensure the raw form is exactly the bytes of the value.
+            let expected_bytes = expected.to_bytes_le()?;
+            assert_eq!(expected_bytes, bincode::serialize(&Raw(&expected))?);
+            assert_eq!(expected, bincode::deserialize::<RawOwned>(&expected_bytes)?.0);
+
+            // Ensure the raw form is delimited correctly as a field, followed by another field.
+            let candidate_bytes = bincode::serialize(&(Raw(&expected), 7u64))?;
+            assert_eq!([expected_bytes.clone(), 7u64.to_le_bytes().to_vec()].concat(), candidate_bytes);
+            let (candidate, tag) = bincode::deserialize::<(RawOwned, u64)>(&candidate_bytes)?;
+            assert_eq!(expected, candidate.0);
+            assert_eq!(7, tag);
+
+            // Ensure truncated bytes fail to deserialize.
+            assert!(bincode::deserialize::<RawOwned>(&expected_bytes[..expected_bytes.len() - 1]).is_err());
+
+            // Ensure the human-readable form is unchanged.
+            let candidate_string = serde_json::to_string(&Raw(&expected))?;
+            assert_eq!(serde_json::to_string(&expected)?, candidate_string);
+            assert_eq!(expected, serde_json::from_str::<RawOwned>(&candidate_string)?.0);
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_bincode() -> Result<()> {
         {
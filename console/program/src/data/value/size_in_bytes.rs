@@ -0,0 +1,59 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Value<N> {
+    /// Returns the number of bytes in the byte representation of this value, without serializing it.
+    pub fn size_in_bytes(&self) -> usize {
+        // The variant is written as a u8.
+        1 + match self {
+            Self::Plaintext(plaintext) => plaintext.size_in_bytes(),
+            Self::Record(record) => record.size_in_bytes(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    fn check_size_in_bytes(value: &str) -> Result<()> {
+        let value = Value::<CurrentNetwork>::from_str(value)?;
+        assert_eq!(value.to_bytes_le()?.len(), value.size_in_bytes(), "{value}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_size_in_bytes_plaintext() -> Result<()> {
+        check_size_in_bytes("5u8")?;
+        check_size_in_bytes("true")?;
+        check_size_in_bytes("\"hello world\"")?;
+        check_size_in_bytes("{ foo: 5u8, bar: { baz: 10field, qux: \"string\" } }")
+    }
+
+    #[test]
+    fn test_size_in_bytes_record() -> Result<()> {
+        let owner = "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah";
+        check_size_in_bytes(&format!("{{ owner: {owner}.private, _nonce: 0group.public }}"))?;
+        check_size_in_bytes(&format!(
+            "{{ owner: {owner}.public, amount: 100u64.private, point: {{ x: 1field.constant, y: 2i8.constant }}, name: \"a\".private, _nonce: 0group.public }}"
+        ))
+    }
+}
@@ -0,0 +1,60 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Value<N> {
+    /// Returns the BHP hash of the canonical byte representation of this value.
+    /// Note: Equal values always share a hash, as the byte representation does not depend on the in-memory layout.
+    pub fn to_hash(&self) -> Result<Field<N>> {
+        N::hash_bhp1024(&self.to_bytes_le()?.to_bits_le())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const RECORD: &str = "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, amount: 100u64.private, _nonce: 0group.public }";
+
+    #[test]
+    fn test_to_hash() -> Result<()> {
+        // Ensure two independently-parsed equal records share a hash.
+        let a = Value::<CurrentNetwork>::from_str(RECORD)?;
+        let b = Value::<CurrentNetwork>::from_str(RECORD)?;
+        assert_eq!(a.to_hash()?, b.to_hash()?);
+
+        // Ensure the hash is computed over the canonical byte representation.
+        let c = Value::<CurrentNetwork>::from_bytes_le(&a.to_bytes_le()?)?;
+        assert_eq!(a.to_hash()?, c.to_hash()?);
+        assert_eq!(CurrentNetwork::hash_bhp1024(&a.to_bytes_le()?.to_bits_le())?, a.to_hash()?);
+
+        // Ensure a different record has a different hash.
+        let d = Value::<CurrentNetwork>::from_str(&RECORD.replace("100u64", "101u64"))?;
+        assert_ne!(a.to_hash()?, d.to_hash()?);
+
+        // Ensure plaintexts with different values or types have different hashes.
+        let hashes = ["5u8", "6u8", "5u16", "{ foo: 5u8 }", "{ bar: 5u8 }"]
+            .iter()
+            .map(|value| Value::<CurrentNetwork>::from_str(value)?.to_hash())
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(hashes.len(), hashes.iter().collect::<std::collections::HashSet<_>>().len());
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Value<N> {
+    /// Returns the value as a single-line string, printing at most `max_fields` members of each record
+    /// or struct, and eliding the remaining members with `...`. This is intended for logging only;
+    /// the canonical string representation is given by `Display`.
+    pub fn to_string_truncated(&self, max_fields: usize) -> String {
+        match self {
+            Self::Plaintext(plaintext) => plaintext_to_string_truncated(plaintext, None, max_fields),
+            Self::Record(record) => {
+                // Prepare the owner and entries of the record.
+                let owner = std::iter::once(format!("owner: {}", record.owner()));
+                let data = record.data().iter().map(|(identifier, entry)| {
+                    let entry = match entry {
+                        Entry::Constant(plaintext) => {
+                            plaintext_to_string_truncated(plaintext, Some("constant"), max_fields)
+                        }
+                        Entry::Public(plaintext) => {
+                            plaintext_to_string_truncated(plaintext, Some("public"), max_fields)
+                        }
+                        Entry::Private(plaintext) => {
+                            plaintext_to_string_truncated(plaintext, Some("private"), max_fields)
+                        }
+                    };
+                    format!("{identifier}: {entry}")
+                });
+                let nonce = std::iter::once(format!("_nonce: {}.public", record.nonce()));
+                members_to_string_truncated(owner.chain(data).chain(nonce), max_fields)
+            }
+        }
+    }
+}
+
+/// Returns the plaintext as a single-line string, with the given visibility appended to each literal.
+fn plaintext_to_string_truncated<N: Network>(
+    plaintext: &Plaintext<N>,
+    mode: Option<&str>,
+    max_fields: usize,
+) -> String {
+    match plaintext {
+        Plaintext::Literal(literal, ..) => match mode {
+            Some(mode) => format!("{literal}.{mode}"),
+            None => literal.to_string(),
+        },
+        Plaintext::Struct(members, ..) => members_to_string_truncated(
+            members.iter().map(|(identifier, plaintext)| {
+                format!("{identifier}: {}", plaintext_to_string_truncated(plaintext, mode, max_fields))
+            }),
+            max_fields,
+        ),
+    }
+}
+
+/// Returns the first `max_fields` members in braces, followed by `...` if any members were elided.
+fn members_to_string_truncated(members: impl Iterator<Item = String>, max_fields: usize) -> String {
+    // Take one additional member, to determine if any members are elided.
+    let mut fields = members.take(max_fields.saturating_add(1)).collect::<Vec<_>>();
+    if fields.len() > max_fields {
+        fields.truncate(max_fields);
+        fields.push("...".to_string());
+    }
+    match fields.is_empty() {
+        true => "{}".to_string(),
+        false => format!("{{ {} }}", fields.join(", ")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_to_string_truncated() -> Result<()> {
+        // Prepare a record with 10 fields, including the owner and nonce.
+        let owner = "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah";
+        let entries = (0..8).map(|i| format!("a{i}: {i}u64.private")).collect::<Vec<_>>().join(", ");
+        let string = format!("{{ owner: {owner}.private, {entries}, _nonce: 0group.public }}");
+        let value = Value::<CurrentNetwork>::from_str(&string)?;
+
+        // Ensure the truncated string contains the first three fields and an ellipsis.
+        let expected = format!("{{ owner: {owner}.private, a0: 0u64.private, a1: 1u64.private, ... }}");
+        assert_eq!(expected, value.to_string_truncated(3));
+
+        // Ensure the string is not truncated if there are enough fields.
+        assert_eq!(string, value.to_string_truncated(10));
+        assert_eq!("{ ... }", value.to_string_truncated(0));
+
+        // Ensure the canonical string is unaffected, and still round trips.
+        assert_eq!(value, Value::from_str(&value.to_string())?);
+        assert!(value.to_string().contains("a7: 7u64.private"));
+
+        // Ensure struct members are truncated.
+        let value = Value::<CurrentNetwork>::from_str("{ x: 1u8, y: { a: 2u8, b: 3u8, c: 4u8 }, z: 5u8 }")?;
+        assert_eq!("{ x: 1u8, y: { a: 2u8, b: 3u8, ... }, ... }", value.to_string_truncated(2));
+        assert_eq!("5u8", Value::<CurrentNetwork>::from_str("5u8")?.to_string_truncated(0));
+        Ok(())
+    }
+}
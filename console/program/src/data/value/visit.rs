@@ -0,0 +1,145 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::Owner;
+
+impl<N: Network> Value<N> {
+    /// Calls `f` with the member path and literal of each leaf in the value, in order.
+    /// For a record, the leaves of the owner and entries are visited, and the nonce is skipped.
+    pub fn visit_leaves<F: FnMut(&[Identifier<N>], &Literal<N>)>(&self, f: F) -> Result<()> {
+        self.visit_leaves_with_nonce(false, f)
+    }
+
+    /// Calls `f` with the member path and literal of each leaf in the value, in order.
+    /// For a record, the leaves of the owner and entries are visited, followed by the nonce if `include_nonce` is set.
+    /// Note: As `_nonce` is not a valid identifier, the nonce is visited with an empty member path.
+    pub fn visit_leaves_with_nonce<F: FnMut(&[Identifier<N>], &Literal<N>)>(
+        &self,
+        include_nonce: bool,
+        mut f: F,
+    ) -> Result<()> {
+        let mut path = Vec::new();
+        match self {
+            Self::Plaintext(plaintext) => visit_plaintext(plaintext, &mut path, &mut f),
+            Self::Record(record) => {
+                // Visit the owner.
+                path.push(Identifier::from_str("owner")?);
+                match record.owner() {
+                    Owner::Public(address) => f(&path, &Literal::Address(*address)),
+                    Owner::Private(plaintext) => visit_plaintext(plaintext, &mut path, &mut f),
+                }
+                path.pop();
+
+                // Visit the entries.
+                for (identifier, entry) in record.data() {
+                    path.push(*identifier);
+                    match entry {
+                        Entry::Constant(plaintext) | Entry::Public(plaintext) | Entry::Private(plaintext) => {
+                            visit_plaintext(plaintext, &mut path, &mut f)
+                        }
+                    }
+                    path.pop();
+                }
+
+                // Visit the nonce, if it is included.
+                if include_nonce {
+                    f(&[], &Literal::Group(*record.nonce()));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Calls `f` with the member path and literal of each leaf in the plaintext, extending the given path.
+pub(super) fn visit_plaintext<N: Network, F: FnMut(&[Identifier<N>], &Literal<N>)>(
+    plaintext: &Plaintext<N>,
+    path: &mut Vec<Identifier<N>>,
+    f: &mut F,
+) {
+    match plaintext {
+        Plaintext::Literal(literal, ..) => f(path, literal),
+        Plaintext::Struct(members, ..) => {
+            for (identifier, member) in members {
+                path.push(*identifier);
+                visit_plaintext(member, path, f);
+                path.pop();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns the member paths and literals of the leaves in the given value, as strings.
+    fn collect_leaves(value: &Value<CurrentNetwork>, include_nonce: bool) -> Result<Vec<(String, String)>> {
+        let mut leaves = Vec::new();
+        value.visit_leaves_with_nonce(include_nonce, |path, literal| {
+            let path = path.iter().map(|identifier| identifier.to_string()).collect::<Vec<_>>().join(".");
+            leaves.push((path, literal.to_string()))
+        })?;
+        Ok(leaves)
+    }
+
+    #[test]
+    fn test_visit_leaves_record() -> Result<()> {
+        let address = "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah";
+        let value = Value::<CurrentNetwork>::from_str(&format!(
+            "{{ owner: {address}.private, amount: 100u64.private, token: {{ id: 5field.public, supply: 7u32.public }}, _nonce: 0group.public }}"
+        ))?;
+
+        // Ensure the callback fires once per leaf, with the member path, and skips the nonce.
+        let expected = vec![
+            ("owner".to_string(), address.to_string()),
+            ("amount".to_string(), "100u64".to_string()),
+            ("token.id".to_string(), "5field".to_string()),
+            ("token.supply".to_string(), "7u32".to_string()),
+        ];
+        assert_eq!(expected, collect_leaves(&value, false)?);
+
+        // Ensure the nonce is visited last with an empty path, if it is included.
+        let mut num_leaves = 0;
+        value.visit_leaves(|_, _| num_leaves += 1)?;
+        assert_eq!(4, num_leaves);
+        let candidate = collect_leaves(&value, true)?;
+        assert_eq!(expected[..], candidate[..4]);
+        assert_eq!(("".to_string(), "0group".to_string()), candidate[4]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_visit_leaves_plaintext() -> Result<()> {
+        // Ensure a literal is visited once with an empty path.
+        let value = Value::<CurrentNetwork>::from_str("1field")?;
+        assert_eq!(vec![("".to_string(), "1field".to_string())], collect_leaves(&value, true)?);
+
+        // Ensure nested struct members are visited in order.
+        let value = Value::<CurrentNetwork>::from_str("{ a: 1u8, b: { c: true, d: { e: 2i16 } } }")?;
+        let expected = vec![
+            ("a".to_string(), "1u8".to_string()),
+            ("b.c".to_string(), "true".to_string()),
+            ("b.d.e".to_string(), "2i16".to_string()),
+        ];
+        assert_eq!(expected, collect_leaves(&value, false)?);
+        Ok(())
+    }
+}
@@ -61,6 +61,11 @@ pub enum LiteralType {
 }
 
 impl LiteralType {
+    /// Returns an iterator over the literal types, in their declared order.
+    pub fn all() -> impl Iterator<Item = Self> {
+        (0u8..).map_while(Self::from_u8)
+    }
+
     /// Returns the literal type name.
     pub fn type_name(&self) -> &str {
         match self {
@@ -19,15 +19,19 @@ pub use literal_type::LiteralType;
 
 mod plaintext_type;
 pub use plaintext_type::PlaintextType;
+use plaintext_type::to_rust_struct_name;
 
 mod record_type;
-pub use record_type::{EntryType, RecordType};
+pub use record_type::{EntryMode, EntryType, RecordMember, RecordType, RecordTypeBuilder, RecordTypeDiff};
 
 mod register_type;
 pub use register_type::RegisterType;
 
 mod struct_;
-pub use struct_::Struct;
+pub use struct_::{Struct, StructBuilder};
+
+mod type_definition;
+pub use type_definition::TypeDefinition;
 
 mod value_type;
 pub use value_type::ValueType;
@@ -0,0 +1,63 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> PlaintextType<N> {
+    /// Checks that the given plaintext matches this plaintext type.
+    /// Note: The members of a struct are not checked, as the struct definition is not available here.
+    pub fn matches(&self, plaintext: &Plaintext<N>) -> Result<()> {
+        match (self, plaintext) {
+            (Self::Literal(literal_type), Plaintext::Literal(literal, ..)) => {
+                ensure!(
+                    literal.to_type() == *literal_type,
+                    "Expected a literal of type '{literal_type}', found '{}'",
+                    literal.to_type()
+                );
+                Ok(())
+            }
+            (Self::Struct(..), Plaintext::Struct(..)) => Ok(()),
+            (Self::Literal(literal_type), Plaintext::Struct(..)) => {
+                bail!("Expected a literal of type '{literal_type}', found a struct")
+            }
+            (Self::Struct(struct_name), Plaintext::Literal(..)) => {
+                bail!("Expected a struct of type '{struct_name}', found a literal")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_matches() -> Result<()> {
+        let literal = Plaintext::<CurrentNetwork>::from_str("5u64")?;
+        let struct_ = Plaintext::<CurrentNetwork>::from_str("{ x: 1field, y: 2field }")?;
+
+        assert!(PlaintextType::<CurrentNetwork>::from_str("u64")?.matches(&literal).is_ok());
+        assert!(PlaintextType::<CurrentNetwork>::from_str("point")?.matches(&struct_).is_ok());
+
+        assert!(PlaintextType::<CurrentNetwork>::from_str("u32")?.matches(&literal).is_err());
+        assert!(PlaintextType::<CurrentNetwork>::from_str("u64")?.matches(&struct_).is_err());
+        assert!(PlaintextType::<CurrentNetwork>::from_str("point")?.matches(&literal).is_err());
+        Ok(())
+    }
+}
@@ -15,10 +15,14 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 mod bytes;
+mod matches;
 mod parse;
+mod rust;
 mod serialize;
 
-use crate::{Identifier, LiteralType};
+pub(crate) use rust::to_rust_struct_name;
+
+use crate::{Identifier, LiteralType, Plaintext};
 use snarkvm_console_network::prelude::*;
 
 /// A `ValueType` defines the type parameter for an entry in an `Struct`.
@@ -0,0 +1,93 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> PlaintextType<N> {
+    /// Returns the Rust type for this plaintext type, for use in generated source code.
+    /// Integers and booleans map to native Rust types, and all other types are generic over `N`.
+    pub(crate) fn rust_type_name(&self) -> String {
+        match self {
+            Self::Literal(literal_type) => match literal_type {
+                LiteralType::Address => "Address<N>".to_string(),
+                LiteralType::Boolean => "bool".to_string(),
+                LiteralType::Field => "Field<N>".to_string(),
+                LiteralType::Group => "Group<N>".to_string(),
+                LiteralType::Scalar => "Scalar<N>".to_string(),
+                LiteralType::String => "String".to_string(),
+                // The remaining literal types are integers, which share their name with the Rust type.
+                integer_type => integer_type.type_name().to_string(),
+            },
+            Self::Struct(identifier) => format!("{}<N>", to_rust_struct_name(identifier)),
+        }
+    }
+
+    /// Returns `true` if the Rust type for this plaintext type is generic over `N`.
+    pub(crate) fn is_rust_type_generic(&self) -> bool {
+        self.rust_type_name().ends_with("<N>")
+    }
+}
+
+/// Returns the Rust struct name for the given identifier, by converting it from snake case to Pascal case.
+pub(crate) fn to_rust_struct_name<N: Network>(identifier: &Identifier<N>) -> String {
+    identifier
+        .to_string()
+        .split('_')
+        .map(|word| {
+            let mut characters = word.chars();
+            match characters.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + characters.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_rust_type_name() -> Result<()> {
+        for (plaintext_type, expected) in [
+            ("address", "Address<N>"),
+            ("boolean", "bool"),
+            ("field", "Field<N>"),
+            ("group", "Group<N>"),
+            ("i64", "i64"),
+            ("u128", "u128"),
+            ("scalar", "Scalar<N>"),
+            ("string", "String"),
+            ("token_metadata", "TokenMetadata<N>"),
+        ] {
+            let plaintext_type = PlaintextType::<CurrentNetwork>::from_str(plaintext_type)?;
+            assert_eq!(expected, plaintext_type.rust_type_name());
+            assert_eq!(expected.ends_with("<N>"), plaintext_type.is_rust_type_generic());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_rust_struct_name() -> Result<()> {
+        for (identifier, expected) in [("token", "Token"), ("token_metadata", "TokenMetadata"), ("a1_b2", "A1B2")] {
+            assert_eq!(expected, to_rust_struct_name(&Identifier::<CurrentNetwork>::from_str(identifier)?));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,158 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A builder for a record type, validating its owner, entries, and defaults on `build`.
+#[derive(Clone)]
+pub struct RecordTypeBuilder<N: Network> {
+    /// The name of the record type.
+    name: Identifier<N>,
+    /// The mode for the owner of the program record.
+    owner: Option<EntryMode>,
+    /// The name and entry type for the entries in data, in their declared order.
+    entries: Vec<(Identifier<N>, EntryType<N>)>,
+    /// The default literals for the entries in data.
+    defaults: Vec<(Identifier<N>, Literal<N>)>,
+}
+
+impl<N: Network> RecordType<N> {
+    /// Returns a builder for a record type with the given name.
+    pub fn builder(name: Identifier<N>) -> RecordTypeBuilder<N> {
+        RecordTypeBuilder { name, owner: None, entries: Vec::new(), defaults: Vec::new() }
+    }
+}
+
+impl<N: Network> RecordTypeBuilder<N> {
+    /// Sets the mode for the owner, which must be public or private.
+    pub fn owner(mut self, mode: EntryMode) -> Self {
+        self.owner = Some(mode);
+        self
+    }
+
+    /// Appends an entry with the given name and entry type.
+    pub fn member(mut self, identifier: Identifier<N>, entry_type: EntryType<N>) -> Self {
+        self.entries.push((identifier, entry_type));
+        self
+    }
+
+    /// Appends an entry with the given name, entry type, and default literal.
+    pub fn member_with_default(
+        mut self,
+        identifier: Identifier<N>,
+        entry_type: EntryType<N>,
+        default: Literal<N>,
+    ) -> Self {
+        self.entries.push((identifier, entry_type));
+        self.defaults.push((identifier, default));
+        self
+    }
+
+    /// Returns the record type, ensuring the owner is set, and the entries are valid.
+    pub fn build(self) -> Result<RecordType<N>> {
+        let name = self.name;
+        // Ensure the owner is set, and is public or private.
+        let owner = match self.owner {
+            Some(EntryMode::Public) => PublicOrPrivate::Public,
+            Some(EntryMode::Private) => PublicOrPrivate::Private,
+            Some(EntryMode::Constant) => bail!("The owner of record '{name}' must be public or private"),
+            None => bail!("The owner of record '{name}' is not set"),
+        };
+        // Ensure the entries has no duplicate names.
+        let reserved = [Identifier::from_str("owner")?];
+        if has_duplicates(self.entries.iter().map(|(identifier, _)| identifier).chain(reserved.iter())) {
+            bail!("Duplicate entry type found in record '{name}'")
+        }
+        // Ensure the number of entries is within the maximum limit.
+        ensure!(self.entries.len() <= N::MAX_DATA_ENTRIES, "Record '{name}' has too many entries");
+        let entries: IndexMap<_, _> = self.entries.into_iter().collect();
+        // Ensure the defaults match the declared literal types, in the declared order of their entries.
+        let mut defaults = IndexMap::with_capacity(self.defaults.len());
+        for (identifier, entry_type) in &entries {
+            if let Some((_, default)) = self.defaults.iter().find(|(default_name, _)| default_name == identifier) {
+                RecordType::check_default(identifier, entry_type, default)?;
+                defaults.insert(*identifier, default.clone());
+            }
+        }
+        Ok(RecordType { name, owner, entries, defaults })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_builder() -> Result<()> {
+        let expected = RecordType::<CurrentNetwork>::from_str(
+            "record message:\n    owner as address.private;\n    first as field.private;\n    second as field.constant;",
+        )?;
+        let candidate = RecordType::builder(Identifier::from_str("message")?)
+            .owner(EntryMode::Private)
+            .member(Identifier::from_str("first")?, EntryType::from_str("field.private")?)
+            .member(Identifier::from_str("second")?, EntryType::from_str("field.constant")?)
+            .build()?;
+        assert_eq!(expected, candidate);
+        assert_eq!(expected.to_string(), candidate.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_builder_fails() -> Result<()> {
+        let name = Identifier::<CurrentNetwork>::from_str("message")?;
+        let first = Identifier::from_str("first")?;
+        let entry_type = EntryType::from_str("field.private")?;
+
+        // Ensure the owner is required, and must not be constant.
+        assert!(RecordType::builder(name).member(first, entry_type).build().is_err());
+        assert!(RecordType::builder(name).owner(EntryMode::Constant).build().is_err());
+        // Ensure duplicate or reserved entry names are rejected.
+        let builder = RecordType::builder(name).owner(EntryMode::Public).member(first, entry_type);
+        assert!(builder.clone().member(first, entry_type).build().is_err());
+        assert!(builder.member(Identifier::from_str("owner")?, entry_type).build().is_err());
+        // Ensure a record with only an owner is accepted.
+        assert!(RecordType::builder(name).owner(EntryMode::Public).build().is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn test_builder_defaults() -> Result<()> {
+        let expected = RecordType::<CurrentNetwork>::from_str(
+            "record message:\n    owner as address.private;\n    first as u8.private = 5u8;\n    second as field.public;",
+        )?;
+        let candidate = RecordType::builder(Identifier::from_str("message")?)
+            .owner(EntryMode::Private)
+            .member_with_default(
+                Identifier::from_str("first")?,
+                EntryType::from_str("u8.private")?,
+                Literal::from_str("5u8")?,
+            )
+            .member(Identifier::from_str("second")?, EntryType::from_str("field.public")?)
+            .build()?;
+        assert_eq!(expected, candidate);
+        assert_eq!(expected.to_string(), candidate.to_string());
+
+        // Ensure a default that does not match its entry type is rejected.
+        let builder = RecordType::<CurrentNetwork>::builder(Identifier::from_str("message")?).owner(EntryMode::Private);
+        let first = Identifier::from_str("first")?;
+        let entry_type = EntryType::from_str("u8.private")?;
+        assert!(builder.member_with_default(first, entry_type, Literal::from_str("5u16")?).build().is_err());
+        Ok(())
+    }
+}
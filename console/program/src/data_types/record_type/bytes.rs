@@ -16,6 +16,10 @@
 
 use super::*;
 
+/// The bit set in the number of entries when the record type declares defaults.
+/// Note: Record types without defaults are encoded exactly as before.
+const DEFAULTS_FLAG: u16 = 1 << 15;
+
 impl<N: Network> FromBytes for RecordType<N> {
     /// Reads a record type from a buffer.
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
@@ -24,8 +28,10 @@ impl<N: Network> FromBytes for RecordType<N> {
         // Read the visibility for the owner.
         let owner = PublicOrPrivate::read_le(&mut reader)?;
 
-        // Read the number of entries.
+        // Read the number of entries, and whether the record type declares defaults.
         let num_entries = u16::read_le(&mut reader)?;
+        let has_defaults = num_entries & DEFAULTS_FLAG != 0;
+        let num_entries = num_entries & !DEFAULTS_FLAG;
         // Ensure the number of entries is within the maximum limit.
         if num_entries as usize > N::MAX_DATA_ENTRIES {
             return Err(error(format!(
@@ -57,7 +63,37 @@ impl<N: Network> FromBytes for RecordType<N> {
             return Err(error("Failed to parse record: too many entries"));
         }
 
-        Ok(Self { name, owner, entries })
+        // Read the defaults.
+        let mut defaults = IndexMap::new();
+        if has_defaults {
+            // Read the number of defaults.
+            let num_defaults = u16::read_le(&mut reader)?;
+            // Ensure the number of defaults is nonzero, and at most the number of entries.
+            if num_defaults == 0 || num_defaults as usize > entries.len() {
+                return Err(error(format!("Invalid number of defaults in record '{name}'")));
+            }
+            let mut last_index = None;
+            for _ in 0..num_defaults {
+                // Read the identifier.
+                let identifier = Identifier::read_le(&mut reader)?;
+                // Read the default literal.
+                let default = Literal::read_le(&mut reader)?;
+                // Ensure the default is for a declared entry.
+                let Some((index, _, entry_type)) = entries.get_full(&identifier) else {
+                    return Err(error(format!("Default for unknown entry '{identifier}' in record '{name}'")));
+                };
+                // Ensure the defaults are in the declared order of their entries, and are unique.
+                if matches!(last_index, Some(last_index) if index <= last_index) {
+                    return Err(error(format!("Defaults in record '{name}' are not in entry order")));
+                }
+                last_index = Some(index);
+                // Ensure the default literal matches the declared literal type.
+                Self::check_default(&identifier, entry_type, &default).map_err(|e| error(e.to_string()))?;
+                defaults.insert(identifier, default);
+            }
+        }
+
+        Ok(Self { name, owner, entries, defaults })
     }
 }
 
@@ -74,8 +110,12 @@ impl<N: Network> ToBytes for RecordType<N> {
         // Write the visibility for the owner.
         self.owner.write_le(&mut writer)?;
 
-        // Write the number of entries.
-        u16::try_from(self.entries.len()).or_halt_with::<N>("Record length exceeds u16").write_le(&mut writer)?;
+        // Write the number of entries, flagging whether the record type declares defaults.
+        let num_entries = u16::try_from(self.entries.len()).or_halt_with::<N>("Record length exceeds u16");
+        match self.defaults.is_empty() {
+            true => num_entries.write_le(&mut writer)?,
+            false => (num_entries | DEFAULTS_FLAG).write_le(&mut writer)?,
+        }
         // Write the entries as bytes.
         for (identifier, value_type) in &self.entries {
             // Write the identifier.
@@ -83,6 +123,19 @@ impl<N: Network> ToBytes for RecordType<N> {
             // Write the value type to the buffer.
             value_type.write_le(&mut writer)?;
         }
+
+        // Write the defaults, if any.
+        if !self.defaults.is_empty() {
+            // Write the number of defaults.
+            u16::try_from(self.defaults.len()).or_halt_with::<N>("Record defaults exceed u16").write_le(&mut writer)?;
+            // Write the defaults as bytes, in the declared order of their entries.
+            for (identifier, default) in &self.defaults {
+                // Write the identifier.
+                identifier.write_le(&mut writer)?;
+                // Write the default literal.
+                default.write_le(&mut writer)?;
+            }
+        }
         Ok(())
     }
 }
@@ -103,4 +156,57 @@ mod tests {
         assert_eq!(expected, candidate);
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_max_entries() -> Result<()> {
+        let entries =
+            |num_entries: usize| (0..num_entries).map(|i| format!("\n    m{i} as u8.private;")).collect::<String>();
+        let expected = RecordType::<CurrentNetwork>::from_str(&format!(
+            "record message:\n    owner as address.private;{}",
+            entries(CurrentNetwork::MAX_DATA_ENTRIES)
+        ))?;
+        assert_eq!(CurrentNetwork::MAX_DATA_ENTRIES, expected.entries().len());
+        let candidate = RecordType::from_bytes_le(&expected.to_bytes_le()?)?;
+        assert_eq!(expected, candidate);
+
+        // Ensure a record type with too many entries is rejected.
+        let mut bytes = expected.to_bytes_le()?;
+        let offset = expected.name().to_bytes_le()?.len() + expected.owner().to_bytes_le()?.len();
+        bytes[offset..offset + 2]
+            .copy_from_slice(&(u16::try_from(CurrentNetwork::MAX_DATA_ENTRIES)? + 1).to_le_bytes());
+        assert!(RecordType::<CurrentNetwork>::from_bytes_le(&bytes).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_defaults() -> Result<()> {
+        let without_defaults = RecordType::<CurrentNetwork>::from_str(
+            "record message:\n    owner as address.private;\n    first as u8.private;\n    second as field.public;",
+        )?;
+        let expected = RecordType::<CurrentNetwork>::from_str(
+            "record message:\n    owner as address.private;\n    first as u8.private = 5u8;\n    second as field.public;",
+        )?;
+        let bytes = expected.to_bytes_le()?;
+        let candidate = RecordType::<CurrentNetwork>::from_bytes_le(&bytes)?;
+        assert_eq!(expected, candidate);
+        assert_eq!(Some(&Literal::from_str("5u8")?), candidate.entry_default(&Identifier::from_str("first")?));
+
+        // Ensure the encoding of a record type without defaults is unchanged, besides the defaults flag.
+        let without_defaults_bytes = without_defaults.to_bytes_le()?;
+        let offset = expected.name().to_bytes_le()?.len() + expected.owner().to_bytes_le()?.len();
+        let mut prefix = bytes[..without_defaults_bytes.len()].to_vec();
+        assert_eq!(DEFAULTS_FLAG.to_le_bytes()[1], prefix[offset + 1] & DEFAULTS_FLAG.to_le_bytes()[1]);
+        prefix[offset + 1] &= !DEFAULTS_FLAG.to_le_bytes()[1];
+        assert_eq!(without_defaults_bytes, prefix);
+        assert_ne!(without_defaults, candidate);
+
+        // Ensure a default that does not match its entry type is rejected.
+        let mut mismatched = bytes.clone();
+        let literal_bytes = Literal::<CurrentNetwork>::from_str("5u8")?.to_bytes_le()?;
+        let len = mismatched.len();
+        mismatched.truncate(len - literal_bytes.len());
+        mismatched.extend(Literal::<CurrentNetwork>::from_str("5u16")?.to_bytes_le()?);
+        assert!(RecordType::<CurrentNetwork>::from_bytes_le(&mismatched).is_err());
+        Ok(())
+    }
 }
@@ -0,0 +1,90 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> RecordType<N> {
+    /// Returns a JSON descriptor of the record type, listing the name, type, and mode of each member.
+    /// The members include the implicit `owner` and `_nonce`, matching the format of a record value.
+    pub fn describe(&self) -> serde_json::Value {
+        // Describe the owner.
+        let owner = serde_json::json!({ "name": "owner", "type": "address", "mode": self.owner.to_string() });
+        // Describe the entries.
+        let entries = self.entries.iter().map(|(identifier, entry_type)| {
+            serde_json::json!({
+                "name": identifier.to_string(),
+                "type": entry_type.plaintext_type().to_string(),
+                "mode": entry_type.mode().to_string(),
+            })
+        });
+        // Describe the nonce.
+        let nonce = serde_json::json!({ "name": "_nonce", "type": "group", "mode": "public" });
+
+        serde_json::json!({
+            "name": self.name.to_string(),
+            "type": Self::type_name(),
+            "members": std::iter::once(owner).chain(entries).chain(std::iter::once(nonce)).collect::<Vec<_>>(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_describe() -> Result<()> {
+        let record_type = RecordType::<CurrentNetwork>::from_str(
+            "record token:\n    owner as address.private;\n    token_amount as u64.private;\n    memo as field.public;\n    kind as u8.constant;",
+        )?;
+        let expected = serde_json::json!({
+            "name": "token",
+            "type": "record",
+            "members": [
+                { "name": "owner", "type": "address", "mode": "private" },
+                { "name": "token_amount", "type": "u64", "mode": "private" },
+                { "name": "memo", "type": "field", "mode": "public" },
+                { "name": "kind", "type": "u8", "mode": "constant" },
+                { "name": "_nonce", "type": "group", "mode": "public" },
+            ]
+        });
+        assert_eq!(expected, record_type.describe());
+
+        // Ensure the members are described in the same order as a record value.
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, token_amount: 100u64.private, memo: 0field.public, kind: 1u8.constant, _nonce: 0group.public }",
+        )?;
+        let names = record_type.describe()["members"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|member| member["name"].as_str().unwrap().to_string())
+            .collect::<Vec<_>>();
+        let expected_names = std::iter::once("owner".to_string())
+            .chain(record.data().keys().map(|identifier| identifier.to_string()))
+            .chain(std::iter::once("_nonce".to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(expected_names, names);
+
+        // Ensure a public owner is described as public.
+        let record_type = RecordType::<CurrentNetwork>::from_str("record token:\n    owner as address.public;")?;
+        assert_eq!("public", record_type.describe()["members"][0]["mode"]);
+        Ok(())
+    }
+}
@@ -0,0 +1,136 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The changes between two versions of a record type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecordTypeDiff<N: Network> {
+    /// Whether the visibility of the owner changed.
+    owner_changed: bool,
+    /// The entries that are only in the new record type.
+    added: Vec<(Identifier<N>, EntryType<N>)>,
+    /// The entries that are only in the old record type.
+    removed: Vec<(Identifier<N>, EntryType<N>)>,
+    /// The entries whose type or mode changed, with their old and new entry types.
+    changed: Vec<(Identifier<N>, EntryType<N>, EntryType<N>)>,
+}
+
+impl<N: Network> RecordTypeDiff<N> {
+    /// Returns `true` if the visibility of the owner changed.
+    pub const fn owner_changed(&self) -> bool {
+        self.owner_changed
+    }
+
+    /// Returns the entries that are only in the new record type, in their declared order.
+    pub fn added(&self) -> &[(Identifier<N>, EntryType<N>)] {
+        &self.added
+    }
+
+    /// Returns the entries that are only in the old record type, in their declared order.
+    pub fn removed(&self) -> &[(Identifier<N>, EntryType<N>)] {
+        &self.removed
+    }
+
+    /// Returns the entries whose type or mode changed, with their old and new entry types.
+    pub fn changed(&self) -> &[(Identifier<N>, EntryType<N>, EntryType<N>)] {
+        &self.changed
+    }
+
+    /// Returns `true` if the record types have the same owner visibility and entries (in any order).
+    pub fn is_empty(&self) -> bool {
+        !self.owner_changed && self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl<N: Network> RecordType<N> {
+    /// Returns the changes from `self` to `other`, as the old and new versions of a record type.
+    /// Note: The record names and the order of the entries are not compared.
+    pub fn diff(&self, other: &RecordType<N>) -> RecordTypeDiff<N> {
+        // Determine the entries that were added, or whose entry type changed.
+        let mut added = Vec::new();
+        let mut changed = Vec::new();
+        for (name, entry_type) in other.entries.iter() {
+            match self.entries.get(name) {
+                None => added.push((*name, *entry_type)),
+                Some(previous) if previous != entry_type => changed.push((*name, *previous, *entry_type)),
+                Some(_) => (),
+            }
+        }
+        // Determine the entries that were removed.
+        let removed = self
+            .entries
+            .iter()
+            .filter(|(name, _)| !other.entries.contains_key(*name))
+            .map(|(name, entry_type)| (*name, *entry_type))
+            .collect();
+
+        RecordTypeDiff { owner_changed: self.owner != other.owner, added, removed, changed }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_diff() -> Result<()> {
+        let old = RecordType::<CurrentNetwork>::from_str(
+            "record token:\n    owner as address.private;\n    amount as u64.private;\n    memo as field.private;",
+        )?;
+        let new = RecordType::<CurrentNetwork>::from_str(
+            "record token:\n    owner as address.private;\n    amount as u64.public;\n    memo as field.private;\n    expiry as u32.public;",
+        )?;
+
+        // Ensure the flipped mode and the added entry are reported.
+        let diff = old.diff(&new);
+        assert!(!diff.is_empty());
+        assert!(!diff.owner_changed());
+        assert_eq!(&[(Identifier::from_str("expiry")?, EntryType::from_str("u32.public")?)], diff.added());
+        assert!(diff.removed().is_empty());
+        assert_eq!(
+            &[(
+                Identifier::from_str("amount")?,
+                EntryType::from_str("u64.private")?,
+                EntryType::from_str("u64.public")?
+            )],
+            diff.changed()
+        );
+
+        // Ensure the reverse diff reports the entry as removed.
+        let diff = new.diff(&old);
+        assert!(diff.added().is_empty());
+        assert_eq!(&[(Identifier::from_str("expiry")?, EntryType::from_str("u32.public")?)], diff.removed());
+        assert_eq!(1, diff.changed().len());
+
+        // Ensure a record type has no changes from itself, or from a reordering of its entries.
+        assert!(old.diff(&old).is_empty());
+        let reordered = RecordType::<CurrentNetwork>::from_str(
+            "record token:\n    owner as address.private;\n    memo as field.private;\n    amount as u64.private;",
+        )?;
+        assert!(old.diff(&reordered).is_empty());
+
+        // Ensure a change to the owner visibility is reported.
+        let public_owner = RecordType::<CurrentNetwork>::from_str(
+            "record token:\n    owner as address.public;\n    amount as u64.private;\n    memo as field.private;",
+        )?;
+        assert!(old.diff(&public_owner).owner_changed());
+        Ok(())
+    }
+}
@@ -32,3 +32,102 @@ pub enum EntryType<N: Network> {
     /// A private type.
     Private(PlaintextType<N>),
 }
+
+/// The mode of an entry type.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub enum EntryMode {
+    /// A constant entry.
+    Constant,
+    /// A publicly-visible entry.
+    Public,
+    /// A private entry.
+    Private,
+}
+
+impl<N: Network> EntryType<N> {
+    /// Returns the plaintext type of the entry type.
+    pub const fn plaintext_type(&self) -> &PlaintextType<N> {
+        match self {
+            Self::Constant(plaintext_type) | Self::Public(plaintext_type) | Self::Private(plaintext_type) => {
+                plaintext_type
+            }
+        }
+    }
+
+    /// Returns the mode of the entry type.
+    pub const fn mode(&self) -> EntryMode {
+        match self {
+            Self::Constant(..) => EntryMode::Constant,
+            Self::Public(..) => EntryMode::Public,
+            Self::Private(..) => EntryMode::Private,
+        }
+    }
+
+    /// Returns the entry type with the same plaintext type, and the given mode.
+    pub fn with_mode(&self, mode: EntryMode) -> Self {
+        let plaintext_type = *self.plaintext_type();
+        match mode {
+            EntryMode::Constant => Self::Constant(plaintext_type),
+            EntryMode::Public => Self::Public(plaintext_type),
+            EntryMode::Private => Self::Private(plaintext_type),
+        }
+    }
+}
+
+impl Debug for EntryMode {
+    /// Prints the entry mode as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for EntryMode {
+    /// Prints the entry mode as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Constant => write!(f, "constant"),
+            Self::Public => write!(f, "public"),
+            Self::Private => write!(f, "private"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_mode() -> Result<()> {
+        let entry_type = EntryType::<CurrentNetwork>::from_str("address.public")?;
+        assert_eq!(EntryMode::Public, entry_type.mode());
+        assert_eq!(&PlaintextType::from_str("address")?, entry_type.plaintext_type());
+
+        assert_eq!(EntryMode::Constant, EntryType::<CurrentNetwork>::from_str("u8.constant")?.mode());
+        assert_eq!(EntryMode::Private, EntryType::<CurrentNetwork>::from_str("i64.private")?.mode());
+        assert_eq!(EntryMode::Private, EntryType::<CurrentNetwork>::from_str("point.private")?.mode());
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_mode() -> Result<()> {
+        for string in ["address.public", "i64.private", "u8.constant", "point.public"] {
+            let entry_type = EntryType::<CurrentNetwork>::from_str(string)?;
+            let (plaintext_type, _) = string.split_once('.').unwrap();
+
+            for mode in [EntryMode::Constant, EntryMode::Public, EntryMode::Private] {
+                let candidate = entry_type.with_mode(mode);
+                assert_eq!(mode, candidate.mode());
+                assert_eq!(entry_type.plaintext_type(), candidate.plaintext_type());
+                // Ensure the entry type round-trips through `Display`.
+                assert_eq!(format!("{plaintext_type}.{mode}"), candidate.to_string());
+                assert_eq!(candidate, EntryType::from_str(&candidate.to_string())?);
+            }
+            // Ensure the same mode leaves the entry type unchanged.
+            assert_eq!(entry_type, entry_type.with_mode(entry_type.mode()));
+        }
+        Ok(())
+    }
+}
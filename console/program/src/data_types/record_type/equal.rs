@@ -0,0 +1,81 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Eq for RecordType<N> {}
+
+impl<N: Network> PartialEq for RecordType<N> {
+    /// Returns `true` if `self` and `other` are equal.
+    /// Note: The entries (and their defaults) are compared in their declared order.
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.owner == other.owner
+            && self.entries.iter().eq(other.entries.iter())
+            && self.defaults.iter().eq(other.defaults.iter())
+    }
+}
+
+impl<N: Network> core::hash::Hash for RecordType<N> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.owner.hash(state);
+        self.entries.len().hash(state);
+        self.entries.iter().for_each(|entry| entry.hash(state));
+        self.defaults.len().hash(state);
+        self.defaults.iter().for_each(|default| default.hash(state));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    use std::collections::HashSet;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_hash_set() -> Result<()> {
+        let string =
+            "record token:\n    owner as address.private;\n    amount as u64.public;\n    memo as field.private;";
+        let record_type = RecordType::<CurrentNetwork>::from_str(string)?;
+
+        // Ensure inserting an equal record type twice results in one element.
+        let mut set = HashSet::new();
+        assert!(set.insert(record_type.clone()));
+        assert!(!set.insert(RecordType::from_str(string)?));
+        assert_eq!(1, set.len());
+        assert!(set.contains(&record_type));
+
+        // Ensure record types with a different name, owner, entry, or entry order are distinct.
+        for string in [
+            "record coin:\n    owner as address.private;\n    amount as u64.public;\n    memo as field.private;",
+            "record token:\n    owner as address.public;\n    amount as u64.public;\n    memo as field.private;",
+            "record token:\n    owner as address.private;\n    amount as u64.private;\n    memo as field.private;",
+            "record token:\n    owner as address.private;\n    amount as u64.public;",
+            "record token:\n    owner as address.private;\n    memo as field.private;\n    amount as u64.public;",
+            "record token:\n    owner as address.private;\n    amount as u64.public = 1u64;\n    memo as field.private;",
+        ] {
+            let candidate = RecordType::<CurrentNetwork>::from_str(string)?;
+            assert_ne!(record_type, candidate);
+            assert!(set.insert(candidate));
+        }
+        assert_eq!(7, set.len());
+        Ok(())
+    }
+}
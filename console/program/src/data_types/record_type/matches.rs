@@ -0,0 +1,155 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> RecordType<N> {
+    /// Checks that the given record matches the layout of this record type.
+    /// Note: The members of a struct entry are not checked, as the struct definition is not available here.
+    pub fn matches(&self, record: &Record<N, Plaintext<N>>) -> Result<()> {
+        // Ensure the visibility of the record owner matches.
+        ensure!(
+            record.owner().is_public() == self.owner.is_public(),
+            "Visibility of the owner in record '{}' does not match",
+            self.name
+        );
+
+        // Ensure the record contains every entry of the record type, with a matching mode and type.
+        for (entry_name, entry_type) in &self.entries {
+            let entry = match record.data().get(entry_name) {
+                Some(entry) => entry,
+                None => bail!("Record '{}' is missing entry '{entry_name}'", self.name),
+            };
+            let (plaintext, plaintext_type) = match (entry, entry_type) {
+                (Entry::Constant(plaintext), EntryType::Constant(plaintext_type))
+                | (Entry::Public(plaintext), EntryType::Public(plaintext_type))
+                | (Entry::Private(plaintext), EntryType::Private(plaintext_type)) => (plaintext, plaintext_type),
+                _ => bail!("Visibility of entry '{}.{entry_name}' does not match", self.name),
+            };
+            plaintext_type
+                .matches(plaintext)
+                .map_err(|error| anyhow!("Record entry '{}.{entry_name}' is invalid: {error}", self.name))?;
+        }
+        // Ensure the record does not contain any extra entries.
+        if let Some(entry_name) = record.data().keys().find(|entry_name| !self.entries.contains_key(*entry_name)) {
+            bail!("Record '{}' contains an unexpected entry '{entry_name}'", self.name)
+        }
+        // Ensure the entries are in the declared order.
+        ensure!(
+            self.entries.keys().eq(record.data().keys()),
+            "Record '{}' entries are not in the declared order",
+            self.name
+        );
+        Ok(())
+    }
+
+    /// Checks that the given value string is a record that matches the layout of this record type.
+    pub fn check_value_str(&self, value_str: &str) -> Result<()> {
+        Value::<N>::from_str_strict(value_str, self).map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const OWNER: &str = "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah";
+
+    fn sample_record_type() -> Result<RecordType<CurrentNetwork>> {
+        RecordType::from_str("record token: owner as address.private; amount as u64.private; memo as message.public;")
+    }
+
+    fn sample_record(entries: &str) -> Result<Record<CurrentNetwork, Plaintext<CurrentNetwork>>> {
+        Record::from_str(&format!("{{ owner: {OWNER}.private, {entries} _nonce: 0group.public }}"))
+    }
+
+    #[test]
+    fn test_matches() -> Result<()> {
+        let record_type = sample_record_type()?;
+        let record = sample_record("amount: 100u64.private, memo: { id: 1field.public },")?;
+        record_type.matches(&record)
+    }
+
+    #[test]
+    fn test_matches_missing_entry() -> Result<()> {
+        let record_type = sample_record_type()?;
+        let record = sample_record("amount: 100u64.private,")?;
+        let error = record_type.matches(&record).unwrap_err();
+        assert_eq!("Record 'token' is missing entry 'memo'", error.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_matches_extra_entry() -> Result<()> {
+        let record_type = sample_record_type()?;
+        let record = sample_record("amount: 100u64.private, memo: { id: 1field.public }, fee: 1u64.private,")?;
+        let error = record_type.matches(&record).unwrap_err();
+        assert_eq!("Record 'token' contains an unexpected entry 'fee'", error.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_matches_fails() -> Result<()> {
+        let record_type = sample_record_type()?;
+
+        // Ensure a mismatched entry mode is rejected.
+        let record = sample_record("amount: 100u64.public, memo: { id: 1field.public },")?;
+        let error = record_type.matches(&record).unwrap_err();
+        assert_eq!("Visibility of entry 'token.amount' does not match", error.to_string());
+
+        // Ensure a mismatched entry type is rejected.
+        let record = sample_record("amount: 100u32.private, memo: { id: 1field.public },")?;
+        let error = record_type.matches(&record).unwrap_err();
+        assert_eq!(
+            "Record entry 'token.amount' is invalid: Expected a literal of type 'u64', found 'u32'",
+            error.to_string()
+        );
+
+        // Ensure a mismatched owner visibility is rejected.
+        let record = Record::from_str(&format!(
+            "{{ owner: {OWNER}.public, amount: 100u64.private, memo: {{ id: 1field.public }}, _nonce: 0group.public }}"
+        ))?;
+        let error = record_type.matches(&record).unwrap_err();
+        assert_eq!("Visibility of the owner in record 'token' does not match", error.to_string());
+
+        // Ensure entries out of order are rejected.
+        let record = sample_record("memo: { id: 1field.public }, amount: 100u64.private,")?;
+        let error = record_type.matches(&record).unwrap_err();
+        assert_eq!("Record 'token' entries are not in the declared order", error.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_value_str() -> Result<()> {
+        let record_type = RecordType::<CurrentNetwork>::from_str(
+            "record token: owner as address.private; token_amount as u64.private;",
+        )?;
+        let record =
+            format!("{{\n  owner: {OWNER}.private,\n  token_amount: 100u64.private,\n  _nonce: 0group.public\n}}");
+        record_type.check_value_str(&record)?;
+
+        // Ensure a mismatched entry mode is reported.
+        let error = record_type.check_value_str(&record.replace("100u64.private", "100u64.public")).unwrap_err();
+        assert_eq!("Visibility of entry 'token.token_amount' does not match", error.to_string());
+        // Ensure a plaintext value, or an invalid value string, is rejected.
+        assert!(record_type.check_value_str("{ token_amount: 100u64 }").is_err());
+        assert!(record_type.check_value_str("{ owner: 100u64.private").is_err());
+        Ok(())
+    }
+}
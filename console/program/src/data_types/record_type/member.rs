@@ -0,0 +1,113 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A member of a record type, consisting of its name and entry type.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RecordMember<'a, N: Network> {
+    /// The name of the member.
+    name: &'a Identifier<N>,
+    /// The entry type of the member.
+    entry_type: &'a EntryType<N>,
+}
+
+impl<'a, N: Network> RecordMember<'a, N> {
+    /// Returns the name of the member.
+    pub const fn name(&self) -> &'a Identifier<N> {
+        self.name
+    }
+
+    /// Returns the plaintext type of the member.
+    pub const fn type_(&self) -> &'a PlaintextType<N> {
+        self.entry_type.plaintext_type()
+    }
+
+    /// Returns the mode of the member.
+    pub const fn mode(&self) -> EntryMode {
+        self.entry_type.mode()
+    }
+
+    /// Returns the entry type of the member.
+    pub const fn entry_type(&self) -> &'a EntryType<N> {
+        self.entry_type
+    }
+}
+
+impl<N: Network> RecordType<N> {
+    /// Returns an iterator over the entries of the record type, in their declared order.
+    pub fn iter_members(&self) -> impl '_ + ExactSizeIterator<Item = RecordMember<'_, N>> {
+        self.entries.iter().map(|(name, entry_type)| RecordMember { name, entry_type })
+    }
+
+    /// Returns the member paths that are accessible in a record of this type, starting with the `owner`.
+    /// Note: The members of a struct entry are not expanded, as the struct definition is not available here.
+    pub fn member_paths(&self) -> Result<Vec<Vec<Identifier<N>>>> {
+        let owner = Identifier::from_str("owner")?;
+        Ok(std::iter::once(vec![owner]).chain(self.entries.keys().map(|name| vec![*name])).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_iter_members() -> Result<()> {
+        let record_type = RecordType::<CurrentNetwork>::from_str(
+            "record message:\n    owner as address.private;\n    first as field.public;\n    second as i64.private;\n    third as point.constant;",
+        )?;
+
+        // Ensure the names and modes are yielded in order.
+        let candidate =
+            record_type.iter_members().map(|member| (member.name().to_string(), member.mode())).collect::<Vec<_>>();
+        let expected = vec![
+            ("first".to_string(), EntryMode::Public),
+            ("second".to_string(), EntryMode::Private),
+            ("third".to_string(), EntryMode::Constant),
+        ];
+        assert_eq!(expected, candidate);
+
+        // Ensure the types and entry types match the entries.
+        assert_eq!(record_type.entries().len(), record_type.iter_members().len());
+        for (member, (name, entry_type)) in record_type.iter_members().zip_eq(record_type.entries()) {
+            assert_eq!(name, member.name());
+            assert_eq!(entry_type, member.entry_type());
+            assert_eq!(entry_type.plaintext_type(), member.type_());
+        }
+        assert_eq!(&PlaintextType::from_str("point")?, record_type.iter_members().last().unwrap().type_());
+        Ok(())
+    }
+
+    #[test]
+    fn test_member_paths() -> Result<()> {
+        let record_type = RecordType::<CurrentNetwork>::from_str(
+            "record message:\n    owner as address.private;\n    first as field.public;\n    second as i64.private;\n    third as point.constant;",
+        )?;
+
+        // Ensure there is one single-element path per member, in order.
+        let candidate = record_type
+            .member_paths()?
+            .iter()
+            .map(|path| path.iter().map(|name| name.to_string()).collect::<Vec<_>>().join("."))
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["owner", "first", "second", "third"], candidate);
+        Ok(())
+    }
+}
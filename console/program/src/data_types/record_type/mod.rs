@@ -15,22 +15,36 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 mod entry_type;
-pub use entry_type::EntryType;
+pub use entry_type::{EntryMode, EntryType};
 
 mod helpers;
 use helpers::PublicOrPrivate;
 
+mod member;
+pub use member::RecordMember;
+
+mod builder;
+pub use builder::RecordTypeBuilder;
+
+mod diff;
+pub use diff::RecordTypeDiff;
+
 mod bytes;
+mod describe;
+mod equal;
+mod matches;
 mod parse;
+mod rename;
+mod rust;
 mod serialize;
 
-use crate::Identifier;
+use crate::{Entry, Identifier, Literal, Plaintext, PlaintextType, Record, Value};
 use snarkvm_console_network::prelude::*;
 
 use indexmap::IndexMap;
 
 /// The declared layout for program data.
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone)]
 pub struct RecordType<N: Network> {
     /// The name of the record type.
     name: Identifier<N>,
@@ -38,6 +52,8 @@ pub struct RecordType<N: Network> {
     owner: PublicOrPrivate,
     /// The name and value type for the entries in data.
     entries: IndexMap<Identifier<N>, EntryType<N>>,
+    /// The default literals for the entries in data, in the declared order of their entries.
+    defaults: IndexMap<Identifier<N>, Literal<N>>,
 }
 
 impl<N: Network> RecordType<N> {
@@ -55,6 +71,23 @@ impl<N: Network> RecordType<N> {
     pub const fn entries(&self) -> &IndexMap<Identifier<N>, EntryType<N>> {
         &self.entries
     }
+
+    /// Returns the default literal for the given entry, if one was declared.
+    pub fn entry_default(&self, identifier: &Identifier<N>) -> Option<&Literal<N>> {
+        self.defaults.get(identifier)
+    }
+
+    /// Ensures the given default literal matches the declared literal type of the given entry.
+    fn check_default(identifier: &Identifier<N>, entry_type: &EntryType<N>, default: &Literal<N>) -> Result<()> {
+        match entry_type.plaintext_type() {
+            PlaintextType::Literal(literal_type) if default.to_type() == *literal_type => Ok(()),
+            plaintext_type => {
+                bail!(
+                    "Default '{default}' for entry '{identifier}' does not match its declared type '{plaintext_type}'"
+                )
+            }
+        }
+    }
 }
 
 impl<N: Network> TypeName for RecordType<N> {
@@ -22,11 +22,13 @@ impl<N: Network> Parser for RecordType<N> {
     ///   record message:
     ///       owner as address.private;
     ///       user_defined as u64.public;
+    ///       count as u32.public = 0u32;
     /// ```
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
         /// Parses a string into a tuple.
-        fn parse_entry<N: Network>(string: &str) -> ParserResult<(Identifier<N>, EntryType<N>)> {
+        #[allow(clippy::type_complexity)]
+        fn parse_entry<N: Network>(string: &str) -> ParserResult<(Identifier<N>, EntryType<N>, Option<Literal<N>>)> {
             // Parse the whitespace and comments from the string.
             let (string, _) = Sanitizer::parse(string)?;
             // Parse the identifier from the string.
@@ -41,10 +43,18 @@ impl<N: Network> Parser for RecordType<N> {
             let (string, value_type) = EntryType::parse(string)?;
             // Parse the whitespace from the string.
             let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the optional default literal from the string.
+            let (string, default) = opt(map_res(
+                pair(pair(tag("="), Sanitizer::parse_whitespaces), Literal::parse),
+                // Ensure the default literal matches the declared literal type.
+                |(_, literal)| RecordType::check_default(&identifier, &value_type, &literal).map(|_| literal),
+            ))(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
             // Parse the semicolon ';' keyword from the string.
             let (string, _) = tag(";")(string)?;
-            // Return the identifier and value type.
-            Ok((string, (identifier, value_type)))
+            // Return the identifier, value type, and default.
+            Ok((string, (identifier, value_type, default)))
         }
 
         // Parse the whitespace and comments from the string.
@@ -85,7 +95,7 @@ impl<N: Network> Parser for RecordType<N> {
             // Prepare the reserved entry names.
             let reserved = [Identifier::from_str("owner").map_err(|e| error(e.to_string()))?];
             // Ensure the entries has no duplicate names.
-            if has_duplicates(entries.iter().map(|(identifier, _)| identifier).chain(reserved.iter())) {
+            if has_duplicates(entries.iter().map(|(identifier, ..)| identifier).chain(reserved.iter())) {
                 return Err(error(format!("Duplicate entry type found in record '{name}'")));
             }
             // Ensure the number of members is within the maximum limit.
@@ -95,8 +105,20 @@ impl<N: Network> Parser for RecordType<N> {
             Ok(entries)
         })(string)?;
 
+        // Split the entry types from their default literals.
+        let mut defaults = IndexMap::new();
+        let entries = entries
+            .into_iter()
+            .map(|(identifier, entry_type, default)| {
+                if let Some(default) = default {
+                    defaults.insert(identifier, default);
+                }
+                (identifier, entry_type)
+            })
+            .collect();
+
         // Return the record type.
-        Ok((string, Self { name, owner, entries: IndexMap::from_iter(entries.into_iter()) }))
+        Ok((string, Self { name, owner, entries, defaults }))
     }
 }
 
@@ -129,7 +151,10 @@ impl<N: Network> Display for RecordType<N> {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         write!(f, "{} {}:", Self::type_name(), self.name)?;
         write!(f, "\n    owner as address.{};", self.owner)?;
-        self.entries.iter().try_for_each(|(entry_name, entry_type)| write!(f, "\n    {entry_name} as {entry_type};"))
+        self.entries.iter().try_for_each(|(entry_name, entry_type)| match self.defaults.get(entry_name) {
+            Some(default) => write!(f, "\n    {entry_name} as {entry_type} = {default};"),
+            None => write!(f, "\n    {entry_name} as {entry_type};"),
+        })
     }
 }
 
@@ -148,6 +173,7 @@ mod tests {
             entries: IndexMap::from_iter(
                 vec![(Identifier::from_str("first")?, EntryType::from_str("field.constant")?)].into_iter(),
             ),
+            defaults: IndexMap::new(),
         };
 
         let (remainder, candidate) = RecordType::<CurrentNetwork>::parse(
@@ -194,6 +220,34 @@ record message:
         assert!(record.is_err());
     }
 
+    #[test]
+    fn test_parse_owner() -> Result<()> {
+        // Ensure a record with a public or private address owner is accepted.
+        let record = RecordType::<CurrentNetwork>::from_str(
+            "record token:\n    owner as address.public;\n    amount as u64.private;",
+        )?;
+        assert!(record.owner().is_public());
+        let record = RecordType::<CurrentNetwork>::from_str(
+            "record token:\n    owner as address.private;\n    amount as u64.private;",
+        )?;
+        assert!(record.owner().is_private());
+
+        // Ensure a record without an owner is rejected.
+        assert!(RecordType::<CurrentNetwork>::from_str("record token:\n    amount as u64.private;").is_err());
+        // Ensure a record whose owner is not the first entry is rejected.
+        assert!(
+            RecordType::<CurrentNetwork>::from_str(
+                "record token:\n    amount as u64.private;\n    owner as address.private;"
+            )
+            .is_err()
+        );
+        // Ensure a record whose owner is not an address is rejected.
+        assert!(RecordType::<CurrentNetwork>::from_str("record token:\n    owner as field.private;").is_err());
+        // Ensure a record whose owner is a constant is rejected.
+        assert!(RecordType::<CurrentNetwork>::from_str("record token:\n    owner as address.constant;").is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_display() {
         let expected = "record message:\n    owner as address.private;\n    first as field.private;\n    second as field.constant;";
@@ -201,6 +255,30 @@ record message:
         assert_eq!(expected, format!("{message}"));
     }
 
+    #[test]
+    fn test_parse_entry_default() -> Result<()> {
+        // Parse a record type with and without defaults.
+        let expected = "record message:\n    owner as address.private;\n    count as u32.public = 0u32;\n    first as field.private;\n    flag as boolean.constant = true;";
+        let message = RecordType::<CurrentNetwork>::from_str(expected)?;
+        assert_eq!(Some(&Literal::from_str("0u32")?), message.entry_default(&Identifier::from_str("count")?));
+        assert_eq!(None, message.entry_default(&Identifier::from_str("first")?));
+        assert_eq!(Some(&Literal::from_str("true")?), message.entry_default(&Identifier::from_str("flag")?));
+        // Ensure the defaults round-trip through display.
+        assert_eq!(expected, message.to_string());
+        assert_eq!(message, RecordType::from_str(&message.to_string())?);
+
+        // Ensure a default of a mismatched type is rejected.
+        for string in [
+            "record message:\n    owner as address.private;\n    count as u32.public = 0u64;",
+            "record message:\n    owner as address.private;\n    count as u32.public = 0field;",
+            "record message:\n    owner as address.private;\n    count as u32.public = ;",
+            "record message:\n    owner as address.private;\n    point as data.public = 0u32;",
+        ] {
+            assert!(RecordType::<CurrentNetwork>::from_str(string).is_err());
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_display_fails() {
         // Duplicate identifier.
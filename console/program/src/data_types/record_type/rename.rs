@@ -0,0 +1,55 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> RecordType<N> {
+    /// Returns a copy of the record type with the given name, preserving its owner and entries.
+    pub fn rename(&self, name: Identifier<N>) -> Result<Self> {
+        // Ensure the new name is a legal identifier, by ensuring it round-trips through its string form.
+        ensure!(Identifier::<N>::from_str(&name.to_string())? == name, "Invalid record name '{name}'");
+        Ok(Self { name, ..self.clone() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_rename() -> Result<()> {
+        let record_type = RecordType::<CurrentNetwork>::from_str(
+            "record token:\n    owner as address.private;\n    amount as u64.private;\n    memo as field.public;",
+        )?;
+
+        // Ensure the name is updated, and the owner and entries are preserved.
+        let candidate = record_type.rename(Identifier::from_str("asset")?)?;
+        assert_eq!("asset", candidate.name().to_string());
+        assert_eq!(record_type.owner(), candidate.owner());
+        assert_eq!(record_type.entries(), candidate.entries());
+
+        // Ensure the display emits the new name.
+        assert_eq!(record_type.to_string().replacen("record token:", "record asset:", 1), candidate.to_string());
+        assert_eq!(candidate, RecordType::from_str(&candidate.to_string())?);
+
+        // Ensure the original record type is unchanged.
+        assert_eq!("token", record_type.name().to_string());
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::data_types::to_rust_struct_name;
+
+impl<N: Network> RecordType<N> {
+    /// Returns the source code of a Rust struct declaration for the record type.
+    /// The fields include the implicit `owner` and `_nonce`, matching the format of a record value.
+    pub fn to_rust_struct(&self) -> String {
+        // Declare the owner.
+        let owner = "    pub owner: Address<N>,\n".to_string();
+        // Declare the entries.
+        let entries = self.entries.iter().map(|(identifier, entry_type)| {
+            format!("    pub {identifier}: {},\n", entry_type.plaintext_type().rust_type_name())
+        });
+        // Declare the nonce.
+        let nonce = "    pub _nonce: Group<N>,\n".to_string();
+
+        format!(
+            "pub struct {}<N: Network> {{\n{}}}\n",
+            to_rust_struct_name(&self.name),
+            std::iter::once(owner).chain(entries).chain(std::iter::once(nonce)).collect::<String>()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_to_rust_struct() -> Result<()> {
+        let record_type = RecordType::<CurrentNetwork>::from_str(
+            "record token:\n    owner as address.private;\n    amount as u64.private;\n    delta as i64.public;\n    memo as field.public;\n    metadata as token_metadata.private;",
+        )?;
+        let expected = "pub struct Token<N: Network> {
+    pub owner: Address<N>,
+    pub amount: u64,
+    pub delta: i64,
+    pub memo: Field<N>,
+    pub metadata: TokenMetadata<N>,
+    pub _nonce: Group<N>,
+}
+";
+        assert_eq!(expected, record_type.to_rust_struct());
+
+        // Ensure a record without entries still declares the owner and nonce.
+        let record_type = RecordType::<CurrentNetwork>::from_str("record empty_token:\n    owner as address.public;")?;
+        let expected =
+            "pub struct EmptyToken<N: Network> {\n    pub owner: Address<N>,\n    pub _nonce: Group<N>,\n}\n";
+        assert_eq!(expected, record_type.to_rust_struct());
+        Ok(())
+    }
+}
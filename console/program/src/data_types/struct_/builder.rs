@@ -0,0 +1,76 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A builder for a struct, validating its members on `build`.
+#[derive(Clone)]
+pub struct StructBuilder<N: Network> {
+    /// The name of the struct.
+    name: Identifier<N>,
+    /// The name and type for the members of the struct, in their declared order.
+    members: Vec<(Identifier<N>, PlaintextType<N>)>,
+}
+
+impl<N: Network> Struct<N> {
+    /// Returns a builder for a struct with the given name.
+    pub fn builder(name: Identifier<N>) -> StructBuilder<N> {
+        StructBuilder { name, members: Vec::new() }
+    }
+}
+
+impl<N: Network> StructBuilder<N> {
+    /// Appends a member with the given name and type.
+    pub fn member(mut self, identifier: Identifier<N>, plaintext_type: PlaintextType<N>) -> Self {
+        self.members.push((identifier, plaintext_type));
+        self
+    }
+
+    /// Returns the struct, ensuring it has at least one member, and no duplicate members.
+    pub fn build(self) -> Result<Struct<N>> {
+        ensure!(!self.members.is_empty(), "Struct '{}' must have at least one member", self.name);
+        Struct::check_members(&self.name, &self.members)?;
+        Ok(Struct { name: self.name, members: self.members.into_iter().collect() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_builder() -> Result<()> {
+        let expected =
+            Struct::<CurrentNetwork>::from_str("struct message:\n    first as field;\n    second as point;")?;
+        let candidate = Struct::builder(Identifier::from_str("message")?)
+            .member(Identifier::from_str("first")?, PlaintextType::from_str("field")?)
+            .member(Identifier::from_str("second")?, PlaintextType::from_str("point")?)
+            .build()?;
+        assert_eq!(expected, candidate);
+
+        // Ensure an empty struct, or a struct with a duplicate member, is rejected.
+        assert!(Struct::<CurrentNetwork>::builder(Identifier::from_str("message")?).build().is_err());
+        let first = Identifier::from_str("first")?;
+        let builder = Struct::<CurrentNetwork>::builder(Identifier::from_str("message")?)
+            .member(first, PlaintextType::from_str("field")?)
+            .member(first, PlaintextType::from_str("u8")?);
+        assert!(builder.build().is_err());
+        Ok(())
+    }
+}
@@ -87,4 +87,24 @@ mod tests {
         assert_eq!(expected, candidate);
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_max_members() -> Result<()> {
+        let members = |num_members: usize| (0..num_members).map(|i| format!("\n    m{i} as u8;")).collect::<String>();
+        let expected = Struct::<CurrentNetwork>::from_str(&format!(
+            "struct message:{}",
+            members(CurrentNetwork::MAX_STRUCT_ENTRIES)
+        ))?;
+        assert_eq!(CurrentNetwork::MAX_STRUCT_ENTRIES, expected.members().len());
+        let candidate = Struct::from_bytes_le(&expected.to_bytes_le()?)?;
+        assert_eq!(expected, candidate);
+
+        // Ensure a struct with too many members is rejected.
+        let mut bytes = expected.to_bytes_le()?;
+        let offset = expected.name().to_bytes_le()?.len();
+        bytes[offset..offset + 2]
+            .copy_from_slice(&(u16::try_from(CurrentNetwork::MAX_STRUCT_ENTRIES)? + 1).to_le_bytes());
+        assert!(Struct::<CurrentNetwork>::from_bytes_le(&bytes).is_err());
+        Ok(())
+    }
 }
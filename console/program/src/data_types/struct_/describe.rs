@@ -0,0 +1,55 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Struct<N> {
+    /// Returns a JSON descriptor of the struct, listing the name and type of each member.
+    pub fn describe(&self) -> serde_json::Value {
+        let members = self.members.iter().map(|(identifier, plaintext_type)| {
+            serde_json::json!({ "name": identifier.to_string(), "type": plaintext_type.to_string() })
+        });
+        serde_json::json!({
+            "name": self.name.to_string(),
+            "type": Self::type_name(),
+            "members": members.collect::<Vec<_>>(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_describe() -> Result<()> {
+        let struct_ =
+            Struct::<CurrentNetwork>::from_str("struct message:\n    sender as address;\n    point as point;")?;
+        let expected = serde_json::json!({
+            "name": "message",
+            "type": "struct",
+            "members": [
+                { "name": "sender", "type": "address" },
+                { "name": "point", "type": "point" },
+            ]
+        });
+        assert_eq!(expected, struct_.describe());
+        Ok(())
+    }
+}
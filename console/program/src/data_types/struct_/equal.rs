@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Eq for Struct<N> {}
+
+impl<N: Network> PartialEq for Struct<N> {
+    /// Returns `true` if `self` and `other` are equal.
+    /// Note: The members are compared in their declared order.
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.members.iter().eq(other.members.iter())
+    }
+}
+
+impl<N: Network> core::hash::Hash for Struct<N> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.members.len().hash(state);
+        self.members.iter().for_each(|member| member.hash(state));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    use std::collections::HashSet;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_hash_set() -> Result<()> {
+        let struct_ =
+            Struct::<CurrentNetwork>::from_str("struct message:\n    sender as address;\n    amount as u64;")?;
+
+        // Ensure inserting an equal struct twice results in one element.
+        let mut set = HashSet::new();
+        assert!(set.insert(struct_.clone()));
+        assert!(!set.insert(Struct::from_str("struct message:\n    sender as address;\n    amount as u64;")?));
+        assert_eq!(1, set.len());
+        assert!(set.contains(&struct_));
+
+        // Ensure structs with a different name, member, or member order are distinct.
+        for string in [
+            "struct note:\n    sender as address;\n    amount as u64;",
+            "struct message:\n    sender as address;\n    amount as u128;",
+            "struct message:\n    sender as address;",
+            "struct message:\n    amount as u64;\n    sender as address;",
+        ] {
+            let candidate = Struct::<CurrentNetwork>::from_str(string)?;
+            assert_ne!(struct_, candidate);
+            assert!(set.insert(candidate));
+        }
+        assert_eq!(5, set.len());
+        Ok(())
+    }
+}
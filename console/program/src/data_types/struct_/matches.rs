@@ -0,0 +1,97 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Struct<N> {
+    /// Checks that the given plaintext matches the layout of this struct.
+    /// Note: The members of a nested struct are not checked, as the struct definition is not available here.
+    pub fn matches(&self, plaintext: &Plaintext<N>) -> Result<()> {
+        // Ensure the plaintext is a struct.
+        let members = match plaintext {
+            Plaintext::Struct(members, ..) => members,
+            Plaintext::Literal(..) => bail!("Expected a struct of type '{}', found a literal", self.name),
+        };
+
+        // Ensure the plaintext contains every member of the struct, with a matching type.
+        for (member_name, member_type) in &self.members {
+            match members.get(member_name) {
+                Some(member) => member_type
+                    .matches(member)
+                    .map_err(|error| anyhow!("Struct member '{}.{member_name}' is invalid: {error}", self.name))?,
+                None => bail!("Struct '{}' is missing member '{member_name}'", self.name),
+            }
+        }
+        // Ensure the plaintext does not contain any extra members.
+        if let Some(member_name) = members.keys().find(|member_name| !self.members.contains_key(*member_name)) {
+            bail!("Struct '{}' contains an unexpected member '{member_name}'", self.name)
+        }
+        // Ensure the members are in the declared order.
+        ensure!(self.members.keys().eq(members.keys()), "Struct '{}' members are not in the declared order", self.name);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    fn sample_struct() -> Result<Struct<CurrentNetwork>> {
+        Struct::from_str("struct line: start as point; length as u64;")
+    }
+
+    #[test]
+    fn test_matches() -> Result<()> {
+        let struct_ = sample_struct()?;
+        let plaintext = Plaintext::from_str("{ start: { x: 1field, y: 2field }, length: 5u64 }")?;
+        struct_.matches(&plaintext)
+    }
+
+    #[test]
+    fn test_matches_fails() -> Result<()> {
+        let struct_ = sample_struct()?;
+
+        // Ensure a literal is rejected.
+        let error = struct_.matches(&Plaintext::from_str("5u64")?).unwrap_err();
+        assert_eq!("Expected a struct of type 'line', found a literal", error.to_string());
+
+        // Ensure a missing member is rejected.
+        let error = struct_.matches(&Plaintext::from_str("{ start: { x: 1field, y: 2field } }")?).unwrap_err();
+        assert_eq!("Struct 'line' is missing member 'length'", error.to_string());
+
+        // Ensure an extra member is rejected.
+        let plaintext = Plaintext::from_str("{ start: { x: 1field, y: 2field }, length: 5u64, width: 1u64 }")?;
+        let error = struct_.matches(&plaintext).unwrap_err();
+        assert_eq!("Struct 'line' contains an unexpected member 'width'", error.to_string());
+
+        // Ensure a member of the wrong type is rejected.
+        let plaintext = Plaintext::from_str("{ start: { x: 1field, y: 2field }, length: 5u32 }")?;
+        let error = struct_.matches(&plaintext).unwrap_err();
+        assert_eq!(
+            "Struct member 'line.length' is invalid: Expected a literal of type 'u64', found 'u32'",
+            error.to_string()
+        );
+
+        // Ensure members out of order are rejected.
+        let plaintext = Plaintext::from_str("{ length: 5u64, start: { x: 1field, y: 2field } }")?;
+        let error = struct_.matches(&plaintext).unwrap_err();
+        assert_eq!("Struct 'line' members are not in the declared order", error.to_string());
+        Ok(())
+    }
+}
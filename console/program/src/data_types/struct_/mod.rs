@@ -14,16 +14,24 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod builder;
+pub use builder::StructBuilder;
+
 mod bytes;
+mod describe;
+mod equal;
+mod matches;
 mod parse;
+mod rename;
+mod rust;
 mod serialize;
 
-use crate::{Identifier, PlaintextType};
+use crate::{Identifier, Plaintext, PlaintextType};
 use snarkvm_console_network::prelude::*;
 
 use indexmap::IndexMap;
 
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone)]
 pub struct Struct<N: Network> {
     /// The name of the struct.
     name: Identifier<N>,
@@ -23,6 +23,10 @@ impl<N: Network> Parser for Struct<N> {
     ///       owner as address;
     ///       amount as u64;
     /// ```
+    ///
+    /// Any whitespace (including tabs and newlines) and comments are accepted before
+    /// the keyword, between the tokens of the header, and before each member.
+    /// The struct name must be followed by a single colon.
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
         /// Parses a string into a tuple.
@@ -60,20 +64,73 @@ impl<N: Network> Parser for Struct<N> {
         // Parse the colon ':' keyword from the string.
         let (string, _) = tag(":")(string)?;
         // Parse the members from the string.
-        let (string, members) = map_res(many1(parse_tuple), |members| {
-            // Ensure the members has no duplicate names.
-            if has_duplicates(members.iter().map(|(identifier, _)| identifier)) {
-                return Err(error(format!("Duplicate identifier found in struct '{name}'")));
-            }
-            // Ensure the number of members is within the maximum limit.
-            if members.len() > N::MAX_STRUCT_ENTRIES {
-                return Err(error("Failed to parse struct: too many members"));
-            }
-            Ok(members)
-        })(string)?;
+        let (string, members) =
+            map_res(many1(parse_tuple), |members| Self::check_members(&name, &members).map(|_| members))(string)?;
+        // Return the struct.
+        Ok((string, Self { name, members: IndexMap::from_iter(members.into_iter()) }))
+    }
+}
+
+impl<N: Network> Struct<N> {
+    /// Parses a struct in its canonical form, as printed by `Display`:
+    /// ```text
+    /// struct message:
+    ///     owner as address;
+    ///     amount as u64;
+    /// ```
+    ///
+    /// Unlike `Struct::parse`, the header must be written as `struct {name}:` with single spaces,
+    /// and each member must be on its own line, indented by exactly four spaces (tabs are rejected).
+    /// Whitespace and comments are still accepted before the keyword.
+    pub fn parse_strict(string: &str) -> ParserResult<Self> {
+        /// Parses a canonically-indented member into a tuple.
+        fn parse_tuple<N: Network>(string: &str) -> ParserResult<(Identifier<N>, PlaintextType<N>)> {
+            // Parse the newline and four-space indentation from the string.
+            let (string, _) = tag("\n    ")(string)?;
+            // Parse the identifier from the string.
+            let (string, identifier) = Identifier::parse(string)?;
+            // Parse the " as " from the string.
+            let (string, _) = tag(" as ")(string)?;
+            // Parse the plaintext type from the string.
+            let (string, plaintext_type) = PlaintextType::parse(string)?;
+            // Parse the semicolon ';' keyword from the string.
+            let (string, _) = tag(";")(string)?;
+            // Return the identifier and plaintext type.
+            Ok((string, (identifier, plaintext_type)))
+        }
+
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the type name from the string.
+        let (string, _) = tag(Self::type_name())(string)?;
+        // Parse the single space from the string.
+        let (string, _) = tag(" ")(string)?;
+        // Parse the struct name from the string.
+        let (string, name) = Identifier::parse(string)?;
+        // Parse the colon ':' keyword from the string.
+        let (string, _) = tag(":")(string)?;
+        // Parse the members from the string.
+        let (string, members) =
+            map_res(many1(parse_tuple), |members| Self::check_members(&name, &members).map(|_| members))(string)?;
         // Return the struct.
         Ok((string, Self { name, members: IndexMap::from_iter(members.into_iter()) }))
     }
+
+    /// Ensures the parsed members have no duplicate names, and are within the maximum limit.
+    pub(super) fn check_members(
+        name: &Identifier<N>,
+        members: &[(Identifier<N>, PlaintextType<N>)],
+    ) -> Result<(), std::io::Error> {
+        // Ensure the members has no duplicate names.
+        if has_duplicates(members.iter().map(|(identifier, _)| identifier)) {
+            return Err(error(format!("Duplicate identifier found in struct '{name}'")));
+        }
+        // Ensure the number of members is within the maximum limit.
+        if members.len() > N::MAX_STRUCT_ENTRIES {
+            return Err(error("Failed to parse struct: too many members"));
+        }
+        Ok(())
+    }
 }
 
 impl<N: Network> FromStr for Struct<N> {
@@ -177,6 +234,72 @@ struct message:
         assert!(struct_.is_err());
     }
 
+    #[test]
+    fn test_parse_tabs() -> Result<()> {
+        let expected =
+            Struct::<CurrentNetwork>::from_str("struct message:\n    sender as address;\n    amount as u64;")?;
+
+        // Ensure the lenient parser accepts tab-indented members.
+        let string = "struct message:\n\tsender as address;\n\tamount as u64;";
+        let (remainder, candidate) = Struct::<CurrentNetwork>::parse(string)?;
+        assert_eq!("", remainder);
+        assert_eq!(expected, candidate);
+
+        // Ensure the lenient parser accepts any horizontal whitespace before members.
+        let string = "struct message:\n \t  sender as address;\n        amount as u64;";
+        assert_eq!(expected, Struct::<CurrentNetwork>::parse(string)?.1);
+
+        // Ensure the strict parser rejects tab-indented members.
+        assert!(
+            Struct::<CurrentNetwork>::parse_strict("struct message:\n\tsender as address;\n\tamount as u64;").is_err()
+        );
+        // Ensure the strict parser stops at the first tab-indented member.
+        let (remainder, candidate) =
+            Struct::<CurrentNetwork>::parse_strict("struct message:\n    sender as address;\n\tamount as u64;")?;
+        assert_eq!("\n\tamount as u64;", remainder);
+        assert_eq!(1, candidate.members().len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_strict() -> Result<()> {
+        let string = "struct message:\n    sender as address;\n    amount as u64;";
+        let expected = Struct::<CurrentNetwork>::from_str(string)?;
+
+        // Ensure the canonical form is accepted.
+        let (remainder, candidate) = Struct::<CurrentNetwork>::parse_strict(string)?;
+        assert_eq!("", remainder);
+        assert_eq!(expected, candidate);
+        assert_eq!(string, candidate.to_string());
+
+        // Ensure leading whitespace before the keyword is accepted.
+        assert_eq!(
+            expected,
+            Struct::<CurrentNetwork>::parse_strict("\n\nstruct message:\n    sender as address;\n    amount as u64;")?
+                .1
+        );
+
+        // Ensure non-canonical forms are rejected.
+        assert!(Struct::<CurrentNetwork>::parse_strict("struct message:\n  sender as address;").is_err());
+        assert!(Struct::<CurrentNetwork>::parse_strict("struct message:\n      sender as address;").is_err());
+        assert!(Struct::<CurrentNetwork>::parse_strict("struct message:    sender as address;").is_err());
+        assert!(Struct::<CurrentNetwork>::parse_strict("struct  message:\n    sender as address;").is_err());
+        assert!(Struct::<CurrentNetwork>::parse_strict("struct message :\n    sender as address;").is_err());
+        assert!(Struct::<CurrentNetwork>::parse_strict("struct message::\n    sender as address;").is_err());
+        assert!(Struct::<CurrentNetwork>::parse_strict("struct message:\n    sender  as address;").is_err());
+        assert!(Struct::<CurrentNetwork>::parse_strict("struct message:\n    sender as address ;").is_err());
+
+        // Ensure the lenient parser rejects a double colon.
+        assert!(Struct::<CurrentNetwork>::parse("struct message::\n    sender as address;").is_err());
+
+        // Ensure duplicate members are rejected.
+        assert!(
+            Struct::<CurrentNetwork>::parse_strict("struct message:\n    first as field;\n    first as field;")
+                .is_err()
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_display() {
         let expected = "struct message:\n    first as field;\n    second as field;";
@@ -0,0 +1,49 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Struct<N> {
+    /// Returns a copy of the struct with the given name, preserving its members.
+    pub fn rename(&self, name: Identifier<N>) -> Result<Self> {
+        // Ensure the new name is a legal identifier, by ensuring it round-trips through its string form.
+        ensure!(Identifier::<N>::from_str(&name.to_string())? == name, "Invalid struct name '{name}'");
+        Ok(Self { name, ..self.clone() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_rename() -> Result<()> {
+        let struct_ = Struct::<CurrentNetwork>::from_str("struct message:\n    first as field;\n    second as u64;")?;
+
+        // Ensure the name is updated, and the members are preserved.
+        let candidate = struct_.rename(Identifier::from_str("letter")?)?;
+        assert_eq!("letter", candidate.name().to_string());
+        assert_eq!(struct_.members(), candidate.members());
+
+        // Ensure the display emits the new name.
+        assert_eq!("struct letter:\n    first as field;\n    second as u64;", candidate.to_string());
+        assert_eq!("message", struct_.name().to_string());
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::data_types::to_rust_struct_name;
+
+impl<N: Network> Struct<N> {
+    /// Returns the source code of a Rust struct declaration for the struct.
+    /// If no member is generic over `N`, a `PhantomData<N>` marker is declared, so that the struct is well-formed.
+    pub fn to_rust_struct(&self) -> String {
+        // Declare the members.
+        let mut fields = self
+            .members
+            .iter()
+            .map(|(identifier, plaintext_type)| format!("    pub {identifier}: {},\n", plaintext_type.rust_type_name()))
+            .collect::<String>();
+        // Declare the marker, if no member uses `N`.
+        if !self.members.values().any(|plaintext_type| plaintext_type.is_rust_type_generic()) {
+            fields.push_str("    pub _phantom: core::marker::PhantomData<N>,\n");
+        }
+
+        format!("pub struct {}<N: Network> {{\n{fields}}}\n", to_rust_struct_name(&self.name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_to_rust_struct() -> Result<()> {
+        let struct_ = Struct::<CurrentNetwork>::from_str(
+            "struct token_metadata:\n    issuer as address;\n    supply as u128;\n    active as boolean;\n    name as string;",
+        )?;
+        let expected = "pub struct TokenMetadata<N: Network> {
+    pub issuer: Address<N>,
+    pub supply: u128,
+    pub active: bool,
+    pub name: String,
+}
+";
+        assert_eq!(expected, struct_.to_rust_struct());
+
+        // Ensure a struct without generic members declares a marker.
+        let struct_ = Struct::<CurrentNetwork>::from_str("struct point:\n    x as i64;\n    y as i64;")?;
+        let expected = "pub struct Point<N: Network> {
+    pub x: i64,
+    pub y: i64,
+    pub _phantom: core::marker::PhantomData<N>,
+}
+";
+        assert_eq!(expected, struct_.to_rust_struct());
+
+        // Ensure a nested struct member is generic over `N`.
+        let struct_ = Struct::<CurrentNetwork>::from_str("struct line:\n    start as point;\n    end as point;")?;
+        let expected = "pub struct Line<N: Network> {\n    pub start: Point<N>,\n    pub end: Point<N>,\n}\n";
+        assert_eq!(expected, struct_.to_rust_struct());
+        Ok(())
+    }
+}
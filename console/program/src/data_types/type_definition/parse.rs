@@ -0,0 +1,124 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Parser for TypeDefinition<N> {
+    /// Parses a string into a struct or record definition.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        alt((map(Struct::parse, Self::Struct), map(RecordType::parse, Self::Record)))(string)
+    }
+}
+
+impl<N: Network> TypeDefinition<N> {
+    /// Parses consecutive struct and record definitions, in order, skipping any whitespace
+    /// and comments between them. Parsing stops at the first input that is not a definition,
+    /// which is returned as the remainder. A malformed definition results in an error.
+    pub fn parse_many(string: &str) -> ParserResult<Vec<Self>> {
+        let mut definitions = Vec::new();
+        let mut string = string;
+        loop {
+            match Self::parse(string) {
+                Ok((remainder, definition)) => {
+                    definitions.push(definition);
+                    string = remainder;
+                }
+                Err(error) => {
+                    // Ensure the parser did not stop on a malformed definition.
+                    let (remainder, _) = Sanitizer::parse(string)?;
+                    if remainder.starts_with(Struct::<N>::type_name())
+                        || remainder.starts_with(RecordType::<N>::type_name())
+                    {
+                        return Err(error);
+                    }
+                    return Ok((string, definitions));
+                }
+            }
+        }
+    }
+}
+
+impl<N: Network> FromStr for TypeDefinition<N> {
+    type Err = Error;
+
+    /// Returns a struct or record definition from a string literal.
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for TypeDefinition<N> {
+    /// Prints the definition as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for TypeDefinition<N> {
+    /// Prints the definition as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Struct(struct_) => Display::fmt(struct_, f),
+            Self::Record(record_type) => Display::fmt(record_type, f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse_many() -> Result<()> {
+        let struct_ = "struct message:\n    first as field;\n    second as u64;";
+        let record = "record token:\n    owner as address.private;\n    amount as u64.private;";
+        let string = format!("\n{struct_}\n\n\n// A comment.\n{record}\n\nfunction foo:");
+
+        // Ensure both definitions are returned in order.
+        let (remainder, definitions) = TypeDefinition::<CurrentNetwork>::parse_many(&string).unwrap();
+        assert_eq!("\n\nfunction foo:", remainder);
+        assert_eq!(2, definitions.len());
+        assert_eq!(TypeDefinition::Struct(Struct::from_str(struct_)?), definitions[0]);
+        assert_eq!(TypeDefinition::Record(RecordType::from_str(record)?), definitions[1]);
+        assert_eq!(struct_, definitions[0].to_string());
+        assert_eq!(record, definitions[1].to_string());
+
+        // Ensure an empty string parses no definitions.
+        assert_eq!(("", vec![]), TypeDefinition::<CurrentNetwork>::parse_many("")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_many_fails_on_malformed_definition() {
+        let string = "struct message:\n    first as field;\n\nrecord token:\n    owner as address;\n\nstruct foo:\n    bar as u8;";
+        assert!(TypeDefinition::<CurrentNetwork>::parse_many(string).is_err());
+
+        let string = "struct message:\n    first as field;\n\nstruct bar:\n";
+        assert!(TypeDefinition::<CurrentNetwork>::parse_many(string).is_err());
+    }
+}
@@ -31,6 +31,7 @@ mod size_in_bytes;
 mod to_bits;
 mod to_field;
 mod zero;
+mod zeroize;
 
 pub use snarkvm_console_network_environment::prelude::*;
 pub use snarkvm_console_types_boolean::Boolean;
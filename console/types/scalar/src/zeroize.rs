@@ -0,0 +1,71 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use ::zeroize::DefaultIsZeroes;
+
+impl<E: Environment> Default for Scalar<E> {
+    /// Returns the zero scalar.
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+/// Zeroizes the scalar by overwriting it with its default value (zero).
+impl<E: Environment> DefaultIsZeroes for Scalar<E> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    use ::zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_zeroize() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let mut scalar = Scalar::<CurrentEnvironment>::rand(&mut rng);
+            scalar.zeroize();
+            assert_eq!(Scalar::zero(), scalar);
+        }
+    }
+
+    #[test]
+    fn test_zeroizing() {
+        let mut rng = TestRng::default();
+
+        // Ensure the wrapper zeroizes the scalar on drop.
+        fn assert_zeroize_on_drop<T: ZeroizeOnDrop>(_: &T) {}
+
+        for _ in 0..ITERATIONS {
+            let expected = Scalar::<CurrentEnvironment>::rand(&mut rng);
+
+            // Ensure the wrapper holds the scalar, until it is zeroized.
+            let mut wrapper = Zeroizing::new(expected);
+            assert_zeroize_on_drop(&wrapper);
+            assert_eq!(expected, *wrapper);
+            wrapper.zeroize();
+            assert_eq!(Scalar::zero(), *wrapper);
+        }
+    }
+}
@@ -0,0 +1,130 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// An accumulator of prover solutions for a single epoch, which is finalized into a coinbase solution.
+///
+/// The proofs of the prover solutions are checked as they are pushed, and only their partial solutions are kept.
+/// Note: As the challenge points are derived from every puzzle commitment, the commitments are combined,
+/// and the coinbase proof is computed, once the accumulator is finalized.
+#[derive(Clone)]
+pub struct Accumulator<N: Network> {
+    /// The coinbase puzzle, used to check and accumulate the prover solutions.
+    puzzle: CoinbasePuzzle<N>,
+    /// The epoch number of the first pushed prover solution.
+    epoch_number: Option<u32>,
+    /// The partial solutions of the pushed prover solutions.
+    partial_solutions: Vec<PartialSolution<N>>,
+}
+
+impl<N: Network> Accumulator<N> {
+    /// Initializes a new, empty accumulator for the given coinbase puzzle.
+    pub const fn new(puzzle: CoinbasePuzzle<N>) -> Self {
+        Self { puzzle, epoch_number: None, partial_solutions: Vec::new() }
+    }
+
+    /// Returns the epoch number of the accumulator, if a prover solution has been pushed.
+    pub const fn epoch_number(&self) -> Option<u32> {
+        self.epoch_number
+    }
+
+    /// Returns the number of accumulated prover solutions.
+    pub fn len(&self) -> usize {
+        self.partial_solutions.len()
+    }
+
+    /// Returns `true` if no prover solutions have been accumulated.
+    pub fn is_empty(&self) -> bool {
+        self.partial_solutions.is_empty()
+    }
+
+    /// Adds the given prover solution for the given epoch challenge to the accumulator.
+    ///
+    /// # Errors
+    /// This method errors if the epoch differs from that of the first pushed prover solution,
+    /// if the prover solution is a duplicate or is invalid for the epoch challenge,
+    /// or if the accumulator is full.
+    pub fn push(&mut self, solution: &ProverSolution<N>, epoch_challenge: &EpochChallenge<N>) -> Result<()> {
+        // Ensure the prover solution is for the epoch of the accumulator.
+        if let Some(epoch_number) = self.epoch_number {
+            ensure!(
+                epoch_number == epoch_challenge.epoch_number(),
+                "Cannot accumulate a prover solution for epoch {} into an accumulator for epoch {epoch_number}",
+                epoch_challenge.epoch_number()
+            );
+        }
+
+        // Ensure the number of prover solutions does not exceed `MAX_PROVER_SOLUTIONS`.
+        ensure!(
+            self.partial_solutions.len() < N::MAX_PROVER_SOLUTIONS,
+            "Cannot accumulate beyond {} prover solutions",
+            N::MAX_PROVER_SOLUTIONS
+        );
+
+        // Ensure the prover solution has not been accumulated already.
+        let is_duplicate = self.partial_solutions.iter().any(|partial| partial.commitment() == solution.commitment());
+        ensure!(!is_duplicate, "Cannot accumulate duplicate prover solutions");
+
+        // Ensure the prover solution is valid for the epoch challenge.
+        ensure!(
+            solution.verify(self.puzzle.coinbase_verifying_key(), epoch_challenge, 0)?,
+            "Cannot accumulate a prover solution that is invalid for epoch {}",
+            epoch_challenge.epoch_number()
+        );
+
+        // Store the partial solution, as the proof is no longer needed.
+        self.epoch_number = Some(epoch_challenge.epoch_number());
+        self.partial_solutions.push(PartialSolution::new(solution.address(), solution.nonce(), solution.commitment()));
+        Ok(())
+    }
+
+    /// Returns the coinbase solution that accumulates the pushed prover solutions, for the given epoch challenge.
+    pub fn finalize(&self, epoch_challenge: &EpochChallenge<N>) -> Result<CoinbaseSolution<N>> {
+        // Ensure there exists prover solutions.
+        let epoch_number = match self.epoch_number {
+            Some(epoch_number) => epoch_number,
+            None => bail!("Cannot finalize an empty accumulator"),
+        };
+        // Ensure the epoch challenge is for the epoch of the accumulator.
+        ensure!(
+            epoch_number == epoch_challenge.epoch_number(),
+            "Cannot finalize an accumulator for epoch {epoch_number} with epoch {}",
+            epoch_challenge.epoch_number()
+        );
+
+        // Retrieve the coinbase proving key.
+        let pk = self.puzzle.coinbase_proving_key()?;
+        // Ensure the epoch polynomial is supported by the proving key.
+        epoch_challenge.ensure_product_domain(&pk.product_domain)?;
+
+        // Compute the prover polynomials.
+        let prover_polynomials = cfg_iter!(self.partial_solutions)
+            .map(|solution| solution.to_prover_polynomial(epoch_challenge))
+            .collect::<Result<Vec<_>>>()?;
+
+        // Accumulate the partial solutions.
+        CoinbasePuzzle::accumulate_partial_solutions(
+            pk,
+            epoch_challenge,
+            prover_polynomials,
+            self.partial_solutions.clone(),
+        )
+    }
+}
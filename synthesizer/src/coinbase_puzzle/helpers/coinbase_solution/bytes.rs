@@ -46,6 +46,46 @@ impl<N: Network> ToBytes for CoinbaseSolution<N> {
     }
 }
 
+impl<N: Network> CoinbaseSolution<N> {
+    /// Reads a length-prefixed sequence of coinbase solutions from the buffer.
+    ///
+    /// # Errors
+    /// This method will halt if the buffer ends before the declared number of solutions is read,
+    /// reporting the number of solutions that were successfully read.
+    pub fn read_le_many<R: Read>(mut reader: R) -> IoResult<Vec<Self>> {
+        // Read the number of solutions.
+        let num_solutions: u32 = FromBytes::read_le(&mut reader)?;
+        // Read the solutions.
+        // Note: The capacity is not preallocated, as the number of solutions is not yet trusted.
+        let mut solutions = Vec::new();
+        for _ in 0..num_solutions {
+            match Self::read_le(&mut reader) {
+                Ok(solution) => solutions.push(solution),
+                Err(e) => {
+                    return Err(error(format!(
+                        "Failed to read coinbase solution {} of {num_solutions} ({} read successfully): {e}",
+                        solutions.len() + 1,
+                        solutions.len()
+                    )));
+                }
+            }
+        }
+        Ok(solutions)
+    }
+
+    /// Writes a length-prefixed sequence of coinbase solutions to the buffer.
+    pub fn write_le_many<W: Write>(mut writer: W, solutions: &[Self]) -> IoResult<()> {
+        // Write the number of solutions.
+        let num_solutions = u32::try_from(solutions.len()).map_err(|e| error(e.to_string()))?;
+        num_solutions.write_le(&mut writer)?;
+        // Write the solutions.
+        for solution in solutions {
+            solution.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -53,19 +93,24 @@ mod tests {
 
     type CurrentNetwork = Testnet3;
 
-    #[test]
-    fn test_bytes() -> Result<()> {
-        let mut rng = TestRng::default();
-
-        // Sample a new coinbase solution.
+    /// Samples a random coinbase solution.
+    fn sample_solution(rng: &mut TestRng) -> Result<CoinbaseSolution<CurrentNetwork>> {
         let mut partial_solutions = vec![];
         for _ in 0..rng.gen_range(1..10) {
-            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
             let address = Address::try_from(private_key)?;
 
-            partial_solutions.push(PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen())));
+            partial_solutions.push(PartialSolution::new(address, u64::rand(rng), KZGCommitment(rng.gen())));
         }
-        let expected = CoinbaseSolution::new(partial_solutions, KZGProof { w: rng.gen(), random_v: None });
+        Ok(CoinbaseSolution::new(partial_solutions, KZGProof { w: rng.gen(), random_v: None }))
+    }
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample a new coinbase solution.
+        let expected = sample_solution(&mut rng)?;
 
         // Check the byte representation.
         let expected_bytes = expected.to_bytes_le()?;
@@ -74,4 +119,40 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_many() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample three coinbase solutions.
+        let expected = (0..3).map(|_| sample_solution(&mut rng)).collect::<Result<Vec<_>>>()?;
+
+        // Ensure the solutions are read back in order.
+        let mut bytes = vec![];
+        CoinbaseSolution::write_le_many(&mut bytes, &expected)?;
+        assert_eq!(expected, CoinbaseSolution::read_le_many(&bytes[..])?);
+
+        // Ensure an empty sequence of solutions is supported.
+        let mut empty = vec![];
+        CoinbaseSolution::<CurrentNetwork>::write_le_many(&mut empty, &[])?;
+        assert!(CoinbaseSolution::<CurrentNetwork>::read_le_many(&empty[..])?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_many_truncated() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample three coinbase solutions.
+        let expected = (0..3).map(|_| sample_solution(&mut rng)).collect::<Result<Vec<_>>>()?;
+        let mut bytes = vec![];
+        CoinbaseSolution::write_le_many(&mut bytes, &expected)?;
+
+        // Ensure a stream that is truncated in the middle of the third solution reports two successful reads.
+        let third_len = expected[2].to_bytes_le()?.len();
+        let truncated = &bytes[..bytes.len() - third_len / 2];
+        let error = CoinbaseSolution::<CurrentNetwork>::read_le_many(truncated).unwrap_err();
+        assert!(error.to_string().starts_with("Failed to read coinbase solution 3 of 3 (2 read successfully)"));
+        Ok(())
+    }
 }
@@ -20,6 +20,8 @@ mod string;
 
 use super::*;
 
+use indexmap::IndexMap;
+
 /// The coinbase puzzle solution constructed by accumulating the individual prover solutions.
 #[derive(Clone, Eq, PartialEq, Hash)]
 pub struct CoinbaseSolution<N: Network> {
@@ -60,6 +62,30 @@ impl<N: Network> CoinbaseSolution<N> {
         self.partial_solutions.is_empty()
     }
 
+    /// Returns the number of individual prover solutions in the coinbase solution.
+    pub fn solution_count(&self) -> usize {
+        self.len()
+    }
+
+    /// Returns the number of partial solutions contributed by each address, in order of first contribution.
+    pub fn contributions(&self) -> IndexMap<Address<N>, usize> {
+        let mut contributions = IndexMap::new();
+        for solution in &self.partial_solutions {
+            *contributions.entry(solution.address()).or_insert(0) += 1;
+        }
+        contributions
+    }
+
+    /// Returns `true` if every commitment was derived from the address and nonce of its partial solution.
+    pub fn matches_commitments(&self, puzzle: &CoinbasePuzzle<N>, epoch_challenge: &EpochChallenge<N>) -> Result<bool> {
+        for solution in &self.partial_solutions {
+            if !solution.matches_commitment(puzzle, epoch_challenge)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
     /// Returns the cumulative sum of the prover solutions.
     pub fn to_cumulative_proof_target(&self) -> Result<u128> {
         // Compute the cumulative target as a u128.
@@ -81,3 +107,39 @@ impl<N: Network> CoinbaseSolution<N> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::PrivateKey, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_contributions() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample two addresses.
+        let alice = Address::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+        let bob = Address::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+
+        // Construct a coinbase solution, where Alice contributes twice.
+        let partial_solutions = [alice, bob, alice]
+            .into_iter()
+            .map(|address| PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen())))
+            .collect::<Vec<_>>();
+        let solution = CoinbaseSolution::new(partial_solutions, KZGProof { w: rng.gen(), random_v: None });
+        assert_eq!(3, solution.solution_count());
+
+        // Ensure the contributions are tallied per address, in order of first contribution.
+        let contributions = solution.contributions();
+        assert_eq!(vec![(alice, 2), (bob, 1)], contributions.into_iter().collect::<Vec<_>>());
+        assert_eq!(solution.solution_count(), solution.contributions().values().sum::<usize>());
+
+        // Ensure an empty coinbase solution has no contributions.
+        let solution = CoinbaseSolution::<CurrentNetwork>::new(vec![], KZGProof { w: rng.gen(), random_v: None });
+        assert_eq!(0, solution.solution_count());
+        assert!(solution.contributions().is_empty());
+        Ok(())
+    }
+}
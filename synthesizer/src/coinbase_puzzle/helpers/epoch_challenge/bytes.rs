@@ -23,10 +23,13 @@ impl<N: Network> FromBytes for EpochChallenge<N> {
         let epoch_number = FromBytes::read_le(&mut reader)?;
         // Read the epoch block hash.
         let epoch_block_hash = FromBytes::read_le(&mut reader)?;
-        // Read the epoch degree.
-        let degree = FromBytes::read_le(&mut reader)?;
+        // Read the number of coefficients.
+        let num_coefficients: u32 = FromBytes::read_le(&mut reader)?;
+        // Read the coefficients of the epoch polynomial.
+        let coefficients =
+            (0..num_coefficients).map(|_| FromBytes::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
         // Return the epoch challenge.
-        Self::new(epoch_number, epoch_block_hash, degree).map_err(|e| error(e.to_string()))
+        Self::from_coefficients(epoch_number, epoch_block_hash, coefficients).map_err(|e| error(e.to_string()))
     }
 }
 
@@ -37,8 +40,10 @@ impl<N: Network> ToBytes for EpochChallenge<N> {
         self.epoch_number.write_le(&mut writer)?;
         // Write the epoch block hash.
         self.epoch_block_hash.write_le(&mut writer)?;
-        // Write the epoch degree.
-        self.degree().write_le(&mut writer)
+        // Write the number of coefficients.
+        self.num_coefficients().map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the coefficients of the epoch polynomial.
+        self.coefficients().iter().try_for_each(|coefficient| coefficient.write_le(&mut writer))
     }
 }
 
@@ -73,4 +78,37 @@ mod tests {
             assert!(EpochChallenge::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
         }
     }
+
+    #[test]
+    fn test_bytes_from_coefficients() -> Result<()> {
+        let mut rng = TestRng::default();
+        let (epoch_number, epoch_block_hash) = (rng.next_u32(), rng.gen());
+
+        // Ensure an epoch challenge with the coefficients of the derived epoch polynomial round-trips.
+        let expected = EpochChallenge::<CurrentNetwork>::new(epoch_number, epoch_block_hash, 32)?;
+        let coefficients = expected.coefficients().to_vec();
+        let candidate =
+            EpochChallenge::<CurrentNetwork>::from_coefficients(epoch_number, epoch_block_hash, coefficients)?;
+        assert_eq!(expected, EpochChallenge::read_le(&candidate.to_bytes_le()?[..])?);
+
+        // Ensure an epoch challenge with other coefficients round-trips.
+        let coefficients = (0..33).map(|_| Uniform::rand(&mut rng)).collect::<Vec<_>>();
+        let expected =
+            EpochChallenge::<CurrentNetwork>::from_coefficients(epoch_number, epoch_block_hash, coefficients)?;
+        assert_eq!(expected, EpochChallenge::read_le(&expected.to_bytes_le()?[..])?);
+
+        // Ensure a constant epoch polynomial round-trips.
+        let coefficients = vec![Uniform::rand(&mut rng)];
+        let expected =
+            EpochChallenge::<CurrentNetwork>::from_coefficients(epoch_number, epoch_block_hash, coefficients)?;
+        assert_eq!(0, expected.degree());
+        assert_eq!(expected, EpochChallenge::read_le(&expected.to_bytes_le()?[..])?);
+
+        // Ensure an all-zero epoch polynomial is rejected.
+        let mut bytes = expected.to_bytes_le()?;
+        let len = bytes.len();
+        bytes[len - 32..].fill(0);
+        assert!(EpochChallenge::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+        Ok(())
+    }
 }
@@ -0,0 +1,101 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// An epoch challenge whose epoch polynomial is guaranteed to have degree `D`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixedEpochChallenge<N: Network, const D: usize> {
+    /// The epoch challenge, with an epoch polynomial of degree `D`.
+    epoch_challenge: EpochChallenge<N>,
+}
+
+impl<N: Network, const D: usize> FixedEpochChallenge<N, D> {
+    /// The degree of the epoch polynomial.
+    pub const DEGREE: usize = D;
+
+    /// Initializes a new epoch challenge, with an epoch polynomial of degree `D`.
+    pub fn new(epoch_number: u32, epoch_block_hash: N::BlockHash) -> Result<Self> {
+        let degree = u32::try_from(D).map_err(|_| anyhow!("The epoch polynomial degree ({D}) is too large"))?;
+        Self::try_from(EpochChallenge::new(epoch_number, epoch_block_hash, degree)?)
+    }
+
+    /// Returns the epoch challenge.
+    pub const fn epoch_challenge(&self) -> &EpochChallenge<N> {
+        &self.epoch_challenge
+    }
+}
+
+impl<N: Network, const D: usize> TryFrom<EpochChallenge<N>> for FixedEpochChallenge<N, D> {
+    type Error = Error;
+
+    /// Returns a fixed-degree epoch challenge, ensuring the epoch polynomial has degree `D`.
+    fn try_from(epoch_challenge: EpochChallenge<N>) -> Result<Self> {
+        let degree = epoch_challenge.epoch_polynomial().degree();
+        ensure!(degree == D, "The epoch polynomial degree ({degree}) does not match the expected degree ({D})");
+        Ok(Self { epoch_challenge })
+    }
+}
+
+impl<N: Network, const D: usize> From<FixedEpochChallenge<N, D>> for EpochChallenge<N> {
+    /// Returns the epoch challenge.
+    fn from(fixed: FixedEpochChallenge<N, D>) -> Self {
+        fixed.epoch_challenge
+    }
+}
+
+impl<N: Network, const D: usize> Deref for FixedEpochChallenge<N, D> {
+    type Target = EpochChallenge<N>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.epoch_challenge
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_fixed_epoch_challenge() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let (epoch_number, epoch_block_hash) = (rng.gen(), rng.gen());
+        let fixed = FixedEpochChallenge::<CurrentNetwork, 256>::new(epoch_number, epoch_block_hash)?;
+        assert_eq!(256, fixed.degree());
+        assert_eq!(epoch_number, fixed.epoch_number());
+
+        // Ensure the conversions round trip.
+        let expected = EpochChallenge::<CurrentNetwork>::new(epoch_number, epoch_block_hash, 256)?;
+        assert_eq!(&expected, fixed.epoch_challenge());
+        assert_eq!(fixed, FixedEpochChallenge::try_from(expected.clone())?);
+        assert_eq!(expected, EpochChallenge::from(fixed));
+        Ok(())
+    }
+
+    #[test]
+    fn test_fixed_epoch_challenge_mismatched_degree() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let epoch_challenge = EpochChallenge::<CurrentNetwork>::new(rng.gen(), rng.gen(), 128)?;
+        let error = FixedEpochChallenge::<CurrentNetwork, 256>::try_from(epoch_challenge).unwrap_err();
+        assert_eq!("The epoch polynomial degree (128) does not match the expected degree (256)", error.to_string());
+        Ok(())
+    }
+}
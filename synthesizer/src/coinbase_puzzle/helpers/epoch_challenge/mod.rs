@@ -15,6 +15,10 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 mod bytes;
+mod fixed;
+mod string;
+
+pub use fixed::FixedEpochChallenge;
 
 use snarkvm_algorithms::fft::Evaluations as EvaluationsOnDomain;
 
@@ -36,12 +40,32 @@ pub struct EpochChallenge<N: Network> {
 impl<N: Network> EpochChallenge<N> {
     /// Initializes a new epoch challenge.
     pub fn new(epoch_number: u32, epoch_block_hash: N::BlockHash, degree: u32) -> Result<Self> {
+        let epoch_polynomial = Self::derive_polynomial(epoch_number, epoch_block_hash, degree)?;
+        let product_domain = CoinbasePuzzle::<N>::product_domain(degree)?;
+        Self::from_polynomial(epoch_number, epoch_block_hash, product_domain, epoch_polynomial)
+    }
+
+    /// Returns the epoch polynomial of the given degree, derived from the epoch number and epoch block hash.
+    fn derive_polynomial(
+        epoch_number: u32,
+        epoch_block_hash: N::BlockHash,
+        degree: u32,
+    ) -> Result<DensePolynomial<<N::PairingCurve as PairingEngine>::Fr>> {
         // Construct the 'input' as '( epoch_number || epoch_block_hash )'
         let input: Vec<u8> = epoch_number.to_le_bytes().into_iter().chain(epoch_block_hash.to_bytes_le()?).collect();
+        Ok(hash_to_polynomial::<<N::PairingCurve as PairingEngine>::Fr>(&input, degree))
+    }
 
-        let product_domain = CoinbasePuzzle::<N>::product_domain(degree)?;
-
-        let epoch_polynomial = hash_to_polynomial::<<N::PairingCurve as PairingEngine>::Fr>(&input, degree);
+    /// Initializes a new epoch challenge from the given epoch polynomial, evaluated over the given product domain.
+    /// Note: An empty or all-zero epoch polynomial is rejected, as it would trivialize the puzzle.
+    fn from_polynomial(
+        epoch_number: u32,
+        epoch_block_hash: N::BlockHash,
+        product_domain: EvaluationDomain<<N::PairingCurve as PairingEngine>::Fr>,
+        epoch_polynomial: DensePolynomial<<N::PairingCurve as PairingEngine>::Fr>,
+    ) -> Result<Self> {
+        // Ensure the epoch polynomial is not degenerate.
+        ensure!(!epoch_polynomial.is_zero(), "The epoch polynomial for epoch {epoch_number} is empty or all-zero");
         ensure!(u32::try_from(epoch_polynomial.degree()).is_ok(), "Degree is too large");
 
         let epoch_polynomial_evaluations = epoch_polynomial.evaluate_over_domain_by_ref(product_domain);
@@ -49,6 +73,62 @@ impl<N: Network> EpochChallenge<N> {
         Ok(EpochChallenge { epoch_number, epoch_block_hash, epoch_polynomial, epoch_polynomial_evaluations })
     }
 
+    /// Initializes a new epoch challenge from the given coefficients of the epoch polynomial, trimming trailing zeros.
+    pub fn from_coefficients(
+        epoch_number: u32,
+        epoch_block_hash: N::BlockHash,
+        coefficients: Vec<<N::PairingCurve as PairingEngine>::Fr>,
+    ) -> Result<Self> {
+        // Construct the epoch polynomial, which trims the trailing zeros.
+        let epoch_polynomial = DensePolynomial::from_coefficients_vec(coefficients);
+        // Ensure the epoch polynomial is not degenerate, before computing its degree.
+        ensure!(!epoch_polynomial.is_zero(), "The epoch polynomial for epoch {epoch_number} is empty or all-zero");
+        let degree = u32::try_from(epoch_polynomial.degree())?;
+
+        // Compute the product domain for the degree of the epoch polynomial.
+        // Note: The product domain is computed directly, as `product_domain` does not support a zero degree.
+        let product_domain = EvaluationDomain::new(2 * degree as usize + 1)
+            .ok_or_else(|| anyhow!("Invalid degree for the epoch polynomial"))?;
+        Self::from_polynomial(epoch_number, epoch_block_hash, product_domain, epoch_polynomial)
+    }
+
+    /// Initializes a new epoch challenge, ensuring the epoch polynomial does not exceed the given maximum degree.
+    pub fn new_with_max_degree(
+        epoch_number: u32,
+        epoch_block_hash: N::BlockHash,
+        degree: u32,
+        max_degree: usize,
+    ) -> Result<Self> {
+        // Ensure the degree is within bounds, before constructing the epoch polynomial.
+        ensure!(
+            degree as usize <= max_degree,
+            "The epoch polynomial degree ({degree}) exceeds the maximum degree ({max_degree})"
+        );
+        Self::new(epoch_number, epoch_block_hash, degree)
+    }
+
+    /// Initializes a new epoch challenge, with the epoch polynomial degree selected for the given difficulty,
+    /// and bounded by the given maximum degree (e.g. the maximum degree of the coinbase proving key).
+    /// Note: The epoch challenge must be proven with the coinbase puzzle from `CoinbasePuzzle::trim_for_difficulty`.
+    pub fn new_for_difficulty(
+        epoch_number: u32,
+        epoch_block_hash: N::BlockHash,
+        difficulty: u64,
+        max_degree: usize,
+    ) -> Result<Self> {
+        let degree = Self::degree_for_difficulty(difficulty).min(max_degree);
+        Self::new_with_max_degree(epoch_number, epoch_block_hash, u32::try_from(degree)?, max_degree)
+    }
+
+    /// Returns the epoch polynomial degree for the given difficulty, as `2^(b + 1) - 1`,
+    /// where `b` is the number of significant bits in the difficulty.
+    /// The degree is non-decreasing in the difficulty, and is capped at `N::COINBASE_PUZZLE_DEGREE`.
+    pub fn degree_for_difficulty(difficulty: u64) -> usize {
+        let max_degree = N::COINBASE_PUZZLE_DEGREE as usize;
+        let num_bits = u64::BITS - difficulty.leading_zeros();
+        1usize.checked_shl(num_bits + 1).map_or(max_degree, |power| (power - 1).min(max_degree))
+    }
+
     /// Returns the epoch number for the solution.
     pub const fn epoch_number(&self) -> u32 {
         self.epoch_number
@@ -64,6 +144,24 @@ impl<N: Network> EpochChallenge<N> {
         &self.epoch_polynomial
     }
 
+    /// Returns the coefficients of the epoch polynomial, without trailing zeros.
+    pub fn coefficients(&self) -> &[<N::PairingCurve as PairingEngine>::Fr] {
+        self.epoch_polynomial.coeffs()
+    }
+
+    /// Returns the evaluation of the epoch polynomial at the given point.
+    pub fn evaluate(&self, point: <N::PairingCurve as PairingEngine>::Fr) -> <N::PairingCurve as PairingEngine>::Fr {
+        self.epoch_polynomial.evaluate(point)
+    }
+
+    /// Returns the evaluations of the epoch polynomial at the given points, in order.
+    pub fn evaluate_batch(
+        &self,
+        points: &[<N::PairingCurve as PairingEngine>::Fr],
+    ) -> Vec<<N::PairingCurve as PairingEngine>::Fr> {
+        points.iter().map(|point| self.evaluate(*point)).collect()
+    }
+
     /// Returns the evaluations of the epoch polynomial over the product domain.
     pub const fn epoch_polynomial_evaluations(&self) -> &EvaluationsOnDomain<<N::PairingCurve as PairingEngine>::Fr> {
         &self.epoch_polynomial_evaluations
@@ -77,9 +175,225 @@ impl<N: Network> EpochChallenge<N> {
         u32::try_from(self.epoch_polynomial.degree()).unwrap()
     }
 
+    /// Ensures the degree of the epoch polynomial does not exceed the given maximum degree.
+    pub fn ensure_degree(&self, max_degree: usize) -> Result<()> {
+        let degree = self.epoch_polynomial.degree();
+        ensure!(
+            degree <= max_degree,
+            "The epoch polynomial degree ({degree}) exceeds the maximum degree ({max_degree})"
+        );
+        Ok(())
+    }
+
+    /// Ensures the epoch polynomial is evaluated over the given product domain,
+    /// which is required to multiply it with a prover polynomial in the product domain.
+    pub fn ensure_product_domain(
+        &self,
+        product_domain: &EvaluationDomain<<N::PairingCurve as PairingEngine>::Fr>,
+    ) -> Result<()> {
+        // Ensure the degree of the epoch polynomial is supported by the product domain.
+        self.ensure_degree((product_domain.size() - 1) / 2)?;
+        // Ensure the epoch polynomial evaluations are over a domain of the same size.
+        let (size, expected_size) = (self.epoch_polynomial_evaluations.domain().size(), product_domain.size());
+        ensure!(
+            size == expected_size,
+            "The epoch polynomial domain size ({size}) does not match the product domain size ({expected_size})"
+        );
+        Ok(())
+    }
+
     /// Returns the number of coefficients of the epoch polynomial.
     pub fn num_coefficients(&self) -> Result<u32> {
         let degree = self.degree();
         degree.checked_add(1).ok_or_else(|| anyhow!("Epoch polynomial degree ({degree} + 1) overflows"))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_ensure_degree() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let epoch_challenge = EpochChallenge::<CurrentNetwork>::new(rng.gen(), rng.gen(), 32)?;
+        assert!(epoch_challenge.ensure_degree(32).is_ok());
+        assert!(epoch_challenge.ensure_degree(64).is_ok());
+
+        let error = epoch_challenge.ensure_degree(31).unwrap_err();
+        assert_eq!("The epoch polynomial degree (32) exceeds the maximum degree (31)", error.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_ensure_product_domain() -> Result<()> {
+        let mut rng = TestRng::default();
+        let product_domain = CoinbasePuzzle::<CurrentNetwork>::product_domain(31)?;
+
+        // Ensure an epoch challenge over the same product domain is accepted.
+        for degree in [16, 31] {
+            let epoch_challenge = EpochChallenge::<CurrentNetwork>::new(rng.gen(), rng.gen(), degree)?;
+            assert!(epoch_challenge.ensure_product_domain(&product_domain).is_ok());
+        }
+
+        // Ensure an epoch challenge over a smaller product domain is rejected.
+        let epoch_challenge = EpochChallenge::<CurrentNetwork>::new(rng.gen(), rng.gen(), 15)?;
+        let error = epoch_challenge.ensure_product_domain(&product_domain).unwrap_err();
+        let expected = "The epoch polynomial domain size (32) does not match the product domain size (64)";
+        assert_eq!(expected, error.to_string());
+
+        // Ensure an epoch challenge over a larger product domain is rejected.
+        let epoch_challenge = EpochChallenge::<CurrentNetwork>::new(rng.gen(), rng.gen(), 32)?;
+        let error = epoch_challenge.ensure_product_domain(&product_domain).unwrap_err();
+        assert_eq!("The epoch polynomial degree (32) exceeds the maximum degree (31)", error.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_with_max_degree() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let (epoch_number, epoch_block_hash) = (rng.gen(), rng.gen());
+        let expected = EpochChallenge::<CurrentNetwork>::new(epoch_number, epoch_block_hash, 32)?;
+        let candidate = EpochChallenge::<CurrentNetwork>::new_with_max_degree(epoch_number, epoch_block_hash, 32, 32)?;
+        assert_eq!(expected, candidate);
+
+        // Ensure an oversized epoch polynomial is rejected.
+        let error = EpochChallenge::<CurrentNetwork>::new_with_max_degree(epoch_number, epoch_block_hash, 1 << 20, 32)
+            .unwrap_err();
+        assert_eq!("The epoch polynomial degree (1048576) exceeds the maximum degree (32)", error.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_polynomial() -> Result<()> {
+        let mut rng = TestRng::default();
+        let (epoch_number, epoch_block_hash) = (rng.gen(), rng.gen());
+
+        // Ensure an empty or all-zero epoch polynomial is rejected.
+        let expected_error = format!("The epoch polynomial for epoch {epoch_number} is empty or all-zero");
+        let product_domain = CoinbasePuzzle::<CurrentNetwork>::product_domain(32)?;
+        let empty = DensePolynomial::from_coefficients_vec(vec![]);
+        let error =
+            EpochChallenge::<CurrentNetwork>::from_polynomial(epoch_number, epoch_block_hash, product_domain, empty)
+                .unwrap_err();
+        assert_eq!(expected_error, error.to_string());
+        let zero = DensePolynomial::from_coefficients_vec(vec![snarkvm_fields::Zero::zero(); 33]);
+        let error =
+            EpochChallenge::<CurrentNetwork>::from_polynomial(epoch_number, epoch_block_hash, product_domain, zero)
+                .unwrap_err();
+        assert_eq!(expected_error, error.to_string());
+
+        // Ensure a nonzero epoch polynomial is accepted.
+        let coefficients = (0..33).map(|_| Uniform::rand(&mut rng)).collect::<Vec<_>>();
+        let polynomial = DensePolynomial::from_coefficients_vec(coefficients);
+        let candidate = EpochChallenge::<CurrentNetwork>::from_polynomial(
+            epoch_number,
+            epoch_block_hash,
+            product_domain,
+            polynomial.clone(),
+        )?;
+        assert_eq!(32, candidate.degree());
+        assert_eq!(&polynomial, candidate.epoch_polynomial());
+
+        // Ensure the epoch challenge from `new` is nonzero, and has the expected degree.
+        let candidate = EpochChallenge::<CurrentNetwork>::new(epoch_number, epoch_block_hash, 32)?;
+        assert!(!candidate.epoch_polynomial().is_zero());
+        assert_eq!(32, candidate.degree());
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_coefficients() -> Result<()> {
+        let mut rng = TestRng::default();
+        let (epoch_number, epoch_block_hash) = (rng.gen(), rng.gen());
+
+        // Ensure the coefficients round-trip.
+        let coefficients = (0..32).map(|_| Uniform::rand(&mut rng)).collect::<Vec<_>>();
+        let candidate =
+            EpochChallenge::<CurrentNetwork>::from_coefficients(epoch_number, epoch_block_hash, coefficients.clone())?;
+        assert_eq!(coefficients, candidate.coefficients());
+        assert_eq!(31, candidate.degree());
+        assert_eq!(epoch_number, candidate.epoch_number());
+
+        // Ensure trailing zeros are trimmed.
+        let mut padded = coefficients.clone();
+        padded.resize(37, snarkvm_fields::Zero::zero());
+        let candidate = EpochChallenge::<CurrentNetwork>::from_coefficients(epoch_number, epoch_block_hash, padded)?;
+        assert_eq!(coefficients, candidate.coefficients());
+        assert_eq!(31, candidate.degree());
+
+        // Ensure the coefficients of a derived epoch challenge reconstruct it.
+        let expected = EpochChallenge::<CurrentNetwork>::new(epoch_number, epoch_block_hash, 32)?;
+        let candidate = EpochChallenge::<CurrentNetwork>::from_coefficients(
+            epoch_number,
+            epoch_block_hash,
+            expected.coefficients().to_vec(),
+        )?;
+        assert_eq!(expected, candidate);
+
+        // Ensure empty or all-zero coefficients are rejected.
+        assert!(EpochChallenge::<CurrentNetwork>::from_coefficients(epoch_number, epoch_block_hash, vec![]).is_err());
+        let zeros = vec![snarkvm_fields::Zero::zero(); 8];
+        assert!(EpochChallenge::<CurrentNetwork>::from_coefficients(epoch_number, epoch_block_hash, zeros).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_evaluate() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let epoch_challenge = EpochChallenge::<CurrentNetwork>::new(rng.gen(), rng.gen(), 32)?;
+        let coefficients = epoch_challenge.coefficients();
+
+        // Ensure evaluating at zero returns the constant coefficient.
+        let zero = snarkvm_fields::Zero::zero();
+        assert_eq!(coefficients[0], epoch_challenge.evaluate(zero));
+
+        // Ensure evaluating at one returns the sum of the coefficients.
+        let one = snarkvm_fields::One::one();
+        let expected = coefficients.iter().fold(zero, |sum, coefficient| sum + coefficient);
+        assert_eq!(expected, epoch_challenge.evaluate(one));
+
+        // Ensure evaluating at a random point matches Horner's method.
+        let point = Uniform::rand(&mut rng);
+        let expected = coefficients.iter().rev().fold(zero, |sum, coefficient| sum * point + coefficient);
+        assert_eq!(expected, epoch_challenge.evaluate(point));
+
+        // Ensure the batch evaluation matches the individual evaluations.
+        let points = (0..8).map(|_| Uniform::rand(&mut rng)).chain([zero, one]).collect::<Vec<_>>();
+        let expected = points.iter().map(|point| epoch_challenge.evaluate(*point)).collect::<Vec<_>>();
+        assert_eq!(expected, epoch_challenge.evaluate_batch(&points));
+        assert!(epoch_challenge.evaluate_batch(&[]).is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_degree_for_difficulty() -> Result<()> {
+        let mut rng = TestRng::default();
+        let max_degree = CurrentNetwork::COINBASE_PUZZLE_DEGREE as usize;
+
+        // Ensure the degree is non-decreasing in the difficulty, and never exceeds the cap.
+        let mut difficulties = (0..64).map(|i| 1u64 << i).chain((0..1000).map(|_| rng.gen())).collect::<Vec<u64>>();
+        difficulties.extend([0, 1, 2, 3, u64::MAX]);
+        difficulties.sort_unstable();
+        let degrees =
+            difficulties.iter().map(|difficulty| EpochChallenge::<CurrentNetwork>::degree_for_difficulty(*difficulty));
+        let degrees = degrees.collect::<Vec<_>>();
+        assert!(degrees.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert!(degrees.iter().all(|degree| *degree >= 1 && *degree <= max_degree));
+        assert_eq!(1, degrees[0]);
+        assert_eq!(max_degree, *degrees.last().unwrap());
+
+        // Ensure the degree is bounded by the given maximum degree.
+        let candidate = EpochChallenge::<CurrentNetwork>::new_for_difficulty(rng.gen(), rng.gen(), u64::MAX, 32)?;
+        assert_eq!(32, candidate.degree());
+        let candidate = EpochChallenge::<CurrentNetwork>::new_for_difficulty(rng.gen(), rng.gen(), 3, 32)?;
+        assert_eq!(7, candidate.degree());
+        Ok(())
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> FromStr for EpochChallenge<N> {
+    type Err = Error;
+
+    /// Initializes the epoch challenge from a string of the form:
+    /// ```text
+    ///   epoch_number: {epoch_number}
+    ///   epoch_block_hash: {epoch_block_hash}
+    ///   {coefficient_0}
+    ///   ...
+    ///   {coefficient_n}
+    /// ```
+    /// where each coefficient of the epoch polynomial is a hex-encoded field element (in little-endian),
+    /// ordered from the lowest to the highest degree.
+    fn from_str(epoch_challenge: &str) -> Result<Self, Self::Err> {
+        let mut lines = epoch_challenge.lines();
+
+        // Parse the epoch number.
+        let epoch_number = match lines.next().and_then(|line| line.strip_prefix("epoch_number: ")) {
+            Some(epoch_number) => epoch_number.parse()?,
+            None => bail!("Missing the epoch number in the epoch challenge"),
+        };
+        // Parse the epoch block hash.
+        let epoch_block_hash = match lines.next().and_then(|line| line.strip_prefix("epoch_block_hash: ")) {
+            Some(epoch_block_hash) => N::BlockHash::from_str(epoch_block_hash)
+                .map_err(|_| anyhow!("Invalid epoch block hash '{epoch_block_hash}' in the epoch challenge"))?,
+            None => bail!("Missing the epoch block hash in the epoch challenge"),
+        };
+        // Parse the coefficients of the epoch polynomial.
+        let coefficients = lines
+            .map(|line| {
+                ensure!(line.len() % 2 == 0, "Invalid hex-encoded coefficient '{line}'");
+                let bytes = (0..line.len())
+                    .step_by(2)
+                    .map(|i| Ok(u8::from_str_radix(line.get(i..i + 2).unwrap_or_default(), 16)?))
+                    .collect::<Result<Vec<_>>>()?;
+                FromBytes::from_bytes_le(&bytes)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        ensure!(!coefficients.is_empty(), "Missing the coefficients of the epoch polynomial");
+
+        // Return the epoch challenge.
+        Self::from_coefficients(epoch_number, epoch_block_hash, coefficients)
+    }
+}
+
+impl<N: Network> Display for EpochChallenge<N> {
+    /// Displays the epoch challenge, with one hex-encoded coefficient of the epoch polynomial per line.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "epoch_number: {}\nepoch_block_hash: {}", self.epoch_number, self.epoch_block_hash)?;
+        for coefficient in &self.epoch_polynomial.coeffs {
+            let bytes = coefficient.to_bytes_le().map_err(|_| fmt::Error)?;
+            write!(f, "\n{}", bytes.iter().map(|byte| format!("{byte:02x}")).collect::<String>())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_string() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new epoch challenge.
+            let degree: u8 = rng.gen();
+            let expected = EpochChallenge::<CurrentNetwork>::new(rng.gen(), rng.gen(), degree.max(1) as u32)?;
+
+            // Check the string representation.
+            let candidate = expected.to_string();
+            assert_eq!(expected.degree() as usize + 3, candidate.lines().count());
+            let candidate = EpochChallenge::<CurrentNetwork>::from_str(&candidate)?;
+            assert_eq!(expected.epoch_polynomial(), candidate.epoch_polynomial());
+            assert_eq!(expected, candidate);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_string_zero_degree() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Initialize an epoch challenge with a constant epoch polynomial.
+        let expected = EpochChallenge::<CurrentNetwork>::new(rng.gen(), rng.gen(), 1)?;
+        let string = expected.to_string();
+        let constant = string.lines().take(3).collect::<Vec<_>>().join("\n");
+
+        let candidate = EpochChallenge::<CurrentNetwork>::from_str(&constant)?;
+        assert_eq!(0, candidate.degree());
+        assert_eq!(expected.epoch_polynomial().coeffs[..1], candidate.epoch_polynomial().coeffs);
+
+        // Ensure the constant epoch challenge survives the round trip.
+        assert_eq!(constant, candidate.to_string());
+        assert_eq!(candidate, EpochChallenge::from_str(&candidate.to_string())?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_string_fails() {
+        let mut rng = TestRng::default();
+        let string = EpochChallenge::<CurrentNetwork>::new(rng.gen(), rng.gen(), 4).unwrap().to_string();
+
+        // Ensure a string without coefficients is rejected.
+        let header = string.lines().take(2).collect::<Vec<_>>().join("\n");
+        assert!(EpochChallenge::<CurrentNetwork>::from_str(&header).is_err());
+        // Ensure a string without a header is rejected.
+        let coefficients = string.lines().skip(2).collect::<Vec<_>>().join("\n");
+        assert!(EpochChallenge::<CurrentNetwork>::from_str(&coefficients).is_err());
+        // Ensure an invalid coefficient is rejected.
+        assert!(EpochChallenge::<CurrentNetwork>::from_str(&format!("{header}\nxyz")).is_err());
+        assert!(EpochChallenge::<CurrentNetwork>::from_str(&format!("{header}\nzz")).is_err());
+    }
+
+    #[test]
+    fn test_string_zero_coefficients() {
+        let mut rng = TestRng::default();
+        let string = EpochChallenge::<CurrentNetwork>::new(rng.gen(), rng.gen(), 4).unwrap().to_string();
+        let header = string.lines().take(2).collect::<Vec<_>>().join("\n");
+        let zero = "0".repeat(string.lines().nth(2).unwrap().len());
+
+        // Ensure an all-zero epoch polynomial is rejected.
+        for num_coefficients in [1, 2, 5] {
+            let candidate = format!("{header}{}", format!("\n{zero}").repeat(num_coefficients));
+            let error = EpochChallenge::<CurrentNetwork>::from_str(&candidate).unwrap_err();
+            assert!(error.to_string().ends_with("is empty or all-zero"));
+        }
+    }
+}
@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod accumulator;
+pub use accumulator::*;
+
 mod coinbase_solution;
 pub use coinbase_solution::*;
 
@@ -35,7 +38,7 @@ use snarkvm_algorithms::{
     fft::{domain::FFTPrecomputation, DensePolynomial, EvaluationDomain},
     polycommit::kzg10::{KZGCommitment, KZGProof, LagrangeBasis, VerifierKey, KZG10},
 };
-use snarkvm_curves::PairingEngine;
+use snarkvm_curves::{AffineCurve, PairingEngine, ProjectiveCurve};
 use snarkvm_utilities::{FromBytes, ToBytes};
 
 use anyhow::Result;
@@ -83,4 +86,38 @@ impl<N: Network> CoinbaseProvingKey<N> {
     pub fn product_domain_elements(&self) -> &[<N::PairingCurve as PairingEngine>::Fr] {
         &self.product_domain_elements
     }
+
+    /// Returns the maximum degree of the epoch polynomial supported by the proving key.
+    /// Note: The product of the epoch and prover polynomials has degree `2n`, which must fit in the product domain.
+    pub fn max_degree(&self) -> usize {
+        (self.product_domain.size() - 1) / 2
+    }
+
+    /// Returns a proving key that is trimmed to support epoch polynomials of at most the given degree.
+    pub fn trim(&self, degree: u32) -> Result<Self> {
+        // Ensure the proving key supports the given degree.
+        ensure!(
+            degree as usize <= self.max_degree(),
+            "The proving key supports a maximum degree of {}, found {degree}",
+            self.max_degree()
+        );
+        let product_domain = CoinbasePuzzle::<N>::product_domain(degree)?;
+
+        // Recover the powers of beta from the Lagrange basis, and compute the Lagrange basis over the trimmed domain.
+        let powers_of_beta_g = self
+            .product_domain
+            .fft(&self.lagrange_basis_at_beta_g.iter().map(|basis| basis.to_projective()).collect::<Vec<_>>());
+        let lagrange_basis_at_beta_g =
+            <N::PairingCurve as PairingEngine>::G1Projective::batch_normalization_into_affine(
+                product_domain.ifft(&powers_of_beta_g[..product_domain.size()]),
+            );
+
+        Ok(Self {
+            lagrange_basis_at_beta_g,
+            product_domain,
+            fft_precomputation: product_domain.precompute_fft(),
+            product_domain_elements: product_domain.elements().collect(),
+            verifying_key: self.verifying_key.clone(),
+        })
+    }
 }
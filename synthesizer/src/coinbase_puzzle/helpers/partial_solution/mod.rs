@@ -61,9 +61,80 @@ impl<N: Network> PartialSolution<N> {
         CoinbasePuzzle::prover_polynomial(epoch_challenge, self.address(), self.nonce())
     }
 
+    /// Returns `true` if the commitment was derived from the address and nonce of the solution.
+    pub fn matches_commitment(&self, puzzle: &CoinbasePuzzle<N>, epoch_challenge: &EpochChallenge<N>) -> Result<bool> {
+        Ok(puzzle.commit(epoch_challenge, self.address, self.nonce)? == self.commitment)
+    }
+
     /// Returns the target of the solution.
+    ///
+    /// The target is computed as `u64::MAX / h`, where `h` is the first 8 bytes (little-endian)
+    /// of the double SHA-256 hash of the commitment bytes. If `h` is zero, the target is `u64::MAX`.
+    /// Note: The nonce is not hashed separately, as it is already bound to the commitment
+    /// through the prover polynomial.
     pub fn to_target(&self) -> Result<u64> {
         let hash_to_u64 = sha256d_to_u64(&self.commitment.to_bytes_le()?);
         if hash_to_u64 == 0 { Ok(u64::MAX) } else { Ok(u64::MAX / hash_to_u64) }
     }
+
+    /// Returns `true` if the target of the solution is greater than or equal to the given target.
+    pub fn meets_target(&self, target: u64) -> Result<bool> {
+        Ok(self.to_target()? >= target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::PrivateKey, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_to_target_is_stable() -> Result<()> {
+        let address =
+            Address::<CurrentNetwork>::from_str("aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah")?;
+        let commitment = PuzzleCommitment::<CurrentNetwork>::default();
+
+        // Ensure the hash of the (empty) commitment bytes is stable.
+        let hash_to_u64 = sha256d_to_u64(&commitment.to_bytes_le()?);
+        assert_eq!(13559968653721601529, hash_to_u64);
+
+        // Ensure the target is derived from the hash, and does not depend on the address or nonce.
+        for nonce in [0, 1, u64::MAX] {
+            let solution = PartialSolution::new(address, nonce, commitment);
+            assert_eq!(u64::MAX / hash_to_u64, solution.to_target()?);
+            assert_eq!(1, solution.to_target()?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_meets_target() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let address = Address::try_from(private_key)?;
+
+            // Sample a new partial solution.
+            let solution = PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen()));
+            let target = solution.to_target()?;
+
+            // Ensure the solution meets every target up to and including its own.
+            assert!(solution.meets_target(0)?);
+            assert!(solution.meets_target(target / 2)?);
+            assert!(solution.meets_target(target.saturating_sub(1))?);
+            assert!(solution.meets_target(target)?);
+
+            // Ensure the solution fails every target above its own.
+            if target < u64::MAX {
+                assert!(!solution.meets_target(target + 1)?);
+                assert!(!solution.meets_target(u64::MAX)?);
+            }
+        }
+        Ok(())
+    }
 }
@@ -20,6 +20,9 @@ mod string;
 
 use super::*;
 
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
 /// The prover solution for the coinbase puzzle from a prover.
 #[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct ProverSolution<N: Network> {
@@ -48,7 +51,7 @@ impl<N: Network> ProverSolution<N> {
         }
 
         // Ensure that the prover solution is greater than the proof target.
-        if self.to_target()? < proof_target {
+        if !self.meets_target(proof_target)? {
             bail!("Prover puzzle does not meet the proof target requirements.")
         }
 
@@ -69,6 +72,67 @@ impl<N: Network> ProverSolution<N> {
         Ok(KZG10::check(verifying_key, &self.commitment(), challenge_point, claimed_value, self.proof())?)
     }
 
+    /// Returns a vector indicating which of the given prover solutions are valid.
+    ///
+    /// The KZG openings are checked together using a random linear combination, so that the pairing
+    /// is computed once for the batch. If the batch check fails, each solution is checked individually.
+    /// Solutions with a hiding proof, or that do not meet the proof target, are marked as invalid.
+    pub fn verify_batch<R: Rng + CryptoRng>(
+        solutions: &[Self],
+        verifying_key: &CoinbaseVerifyingKey<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        proof_target: u64,
+        rng: &mut R,
+    ) -> Result<Vec<bool>> {
+        // Determine the solutions that are non-hiding and meet the proof target.
+        let mut is_valid = solutions
+            .iter()
+            .map(|solution| Ok(!solution.proof.is_hiding() && solution.meets_target(proof_target)?))
+            .collect::<Result<Vec<_>>>()?;
+
+        // Select the candidate solutions for the KZG check.
+        let candidates: Vec<_> = solutions.iter().zip(&is_valid).filter(|(_, valid)| **valid).map(|(s, _)| s).collect();
+        if candidates.is_empty() {
+            return Ok(is_valid);
+        }
+
+        // Compute the challenge point and claimed value for each candidate.
+        let (points, values): (Vec<_>, Vec<_>) = cfg_iter!(candidates)
+            .map(|solution| {
+                // Compute the prover polynomial.
+                let prover_polynomial = solution.partial_solution.to_prover_polynomial(epoch_challenge)?;
+                // Compute the challenge point.
+                let challenge_point = hash_commitment(&solution.commitment())?;
+                // Compute the claimed value by multiplying the evaluations.
+                let claimed_value = epoch_challenge.epoch_polynomial().evaluate(challenge_point)
+                    * prover_polynomial.evaluate(challenge_point);
+                Ok((challenge_point, claimed_value))
+            })
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+        let commitments: Vec<_> = candidates.iter().map(|solution| *solution.commitment()).collect();
+        let proofs: Vec<_> = candidates.iter().map(|solution| *solution.proof()).collect();
+
+        // Check the KZG proofs as a batch.
+        if KZG10::batch_check(verifying_key, &commitments, &points, &values, &proofs, rng)? {
+            return Ok(is_valid);
+        }
+
+        // Otherwise, check each KZG proof individually to determine the invalid solutions.
+        let results = cfg_iter!(commitments)
+            .zip(&points)
+            .zip(&values)
+            .zip(&proofs)
+            .map(|(((commitment, point), value), proof)| {
+                Ok(KZG10::check(verifying_key, commitment, *point, *value, proof)?)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        is_valid.iter_mut().filter(|valid| **valid).zip(results).for_each(|(valid, result)| *valid = result);
+
+        Ok(is_valid)
+    }
+
     /// Returns the address of the prover.
     pub const fn address(&self) -> Address<N> {
         self.partial_solution.address()
@@ -89,6 +153,16 @@ impl<N: Network> ProverSolution<N> {
         &self.proof
     }
 
+    /// Returns `true` if the address, nonce, and commitment of the solutions are equal, ignoring their proofs.
+    pub fn content_eq(&self, other: &Self) -> bool {
+        self.partial_solution == other.partial_solution
+    }
+
+    /// Returns `true` if the commitment was derived from the address and nonce of the solution.
+    pub fn matches_commitment(&self, puzzle: &CoinbasePuzzle<N>, epoch_challenge: &EpochChallenge<N>) -> Result<bool> {
+        self.partial_solution.matches_commitment(puzzle, epoch_challenge)
+    }
+
     /// Returns the prover polynomial.
     pub fn to_prover_polynomial(
         &self,
@@ -101,4 +175,40 @@ impl<N: Network> ProverSolution<N> {
     pub fn to_target(&self) -> Result<u64> {
         self.partial_solution.to_target()
     }
+
+    /// Returns `true` if the target of the solution is greater than or equal to the given target.
+    pub fn meets_target(&self, target: u64) -> Result<bool> {
+        self.partial_solution.meets_target(target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::PrivateKey, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_content_eq() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample a partial solution.
+        let address = Address::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+        let partial_solution = PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen()));
+
+        // Ensure two solutions that differ only in their proofs are `content_eq`, but not equal.
+        let a = ProverSolution::new(partial_solution, KZGProof { w: rng.gen(), random_v: None });
+        let b = ProverSolution::new(partial_solution, KZGProof { w: rng.gen(), random_v: None });
+        assert!(a.content_eq(&b));
+        assert!(b.content_eq(&a));
+        assert_ne!(a, b);
+
+        // Ensure a solution with a different nonce is not `content_eq`.
+        let other =
+            PartialSolution::new(address, partial_solution.nonce().wrapping_add(1), partial_solution.commitment());
+        let c = ProverSolution::new(other, *a.proof());
+        assert!(!a.content_eq(&c));
+        Ok(())
+    }
 }
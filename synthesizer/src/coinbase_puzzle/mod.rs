@@ -106,6 +106,25 @@ impl<N: Network> CoinbasePuzzle<N> {
         Ok(Self::Prover(Arc::new(pk)))
     }
 
+    /// Returns the coinbase puzzle for the given difficulty, with the proving key trimmed to the epoch
+    /// polynomial degree selected for the difficulty. This supports the epoch challenges from
+    /// `EpochChallenge::new_for_difficulty`, when bounded by the maximum degree of this proving key.
+    pub fn trim_for_difficulty(&self, difficulty: u64) -> Result<Self> {
+        // Retrieve the coinbase proving key.
+        let pk = match self {
+            Self::Prover(coinbase_proving_key) => coinbase_proving_key,
+            Self::Verifier(_) => bail!("Cannot trim the coinbase puzzle with a verifier"),
+        };
+
+        // Select the epoch polynomial degree for the difficulty, bounded by the proving key.
+        let degree = u32::try_from(EpochChallenge::<N>::degree_for_difficulty(difficulty).min(pk.max_degree()))?;
+        // If the proving key already has the product domain for the degree, return the coinbase puzzle as is.
+        if Self::product_domain(degree)?.size() == pk.product_domain.size() {
+            return Ok(self.clone());
+        }
+        Ok(Self::Prover(Arc::new(pk.trim(degree)?)))
+    }
+
     /// Returns a prover solution to the coinbase puzzle.
     pub fn prove(
         &self,
@@ -120,18 +139,11 @@ impl<N: Network> CoinbasePuzzle<N> {
             Self::Verifier(_) => bail!("Cannot prove the coinbase puzzle with a verifier"),
         };
 
-        let polynomial = Self::prover_polynomial(epoch_challenge, address, nonce)?;
+        // Ensure the epoch polynomial is supported by the proving key.
+        epoch_challenge.ensure_product_domain(&pk.product_domain)?;
 
-        let product_evaluations = {
-            let polynomial_evaluations = pk.product_domain.in_order_fft_with_pc(&polynomial, &pk.fft_precomputation);
-            let product_evaluations = pk.product_domain.mul_polynomials_in_evaluation_domain(
-                polynomial_evaluations,
-                &epoch_challenge.epoch_polynomial_evaluations().evaluations,
-            );
-            product_evaluations
-        };
-        let (commitment, _rand) =
-            KZG10::commit_lagrange(&pk.lagrange_basis(), &product_evaluations, None, &Default::default(), None)?;
+        let polynomial = Self::prover_polynomial(epoch_challenge, address, nonce)?;
+        let (commitment, product_evaluations) = Self::commit_to_product(pk, epoch_challenge, &polynomial)?;
 
         let partial_solution = PartialSolution::new(address, nonce, commitment);
 
@@ -161,6 +173,27 @@ impl<N: Network> CoinbasePuzzle<N> {
         Ok(ProverSolution::new(partial_solution, proof))
     }
 
+    /// Returns the puzzle commitment for the given epoch challenge, address, and nonce.
+    pub fn commit(
+        &self,
+        epoch_challenge: &EpochChallenge<N>,
+        address: Address<N>,
+        nonce: u64,
+    ) -> Result<PuzzleCommitment<N>> {
+        // Retrieve the coinbase proving key.
+        let pk = match self {
+            Self::Prover(coinbase_proving_key) => coinbase_proving_key,
+            Self::Verifier(_) => bail!("Cannot commit to the coinbase puzzle with a verifier"),
+        };
+
+        // Ensure the epoch polynomial is supported by the proving key.
+        epoch_challenge.ensure_product_domain(&pk.product_domain)?;
+
+        let polynomial = Self::prover_polynomial(epoch_challenge, address, nonce)?;
+        let (commitment, _) = Self::commit_to_product(pk, epoch_challenge, &polynomial)?;
+        Ok(commitment.into())
+    }
+
     /// Returns a coinbase solution for the given epoch challenge and prover solutions.
     ///
     /// # Note
@@ -191,6 +224,9 @@ impl<N: Network> CoinbasePuzzle<N> {
         };
         ensure!(!has_duplicates(prover_solutions), "Cannot accumulate duplicate prover solutions");
 
+        // Ensure the epoch polynomial is supported by the proving key.
+        epoch_challenge.ensure_product_domain(&pk.product_domain)?;
+
         let (prover_polynomials, partial_solutions): (Vec<_>, Vec<_>) = cfg_iter!(prover_solutions)
             .filter_map(|solution| {
                 if solution.proof().is_hiding() {
@@ -201,58 +237,59 @@ impl<N: Network> CoinbasePuzzle<N> {
             })
             .unzip();
 
-        // Compute the challenge points.
-        let mut challenges = hash_commitments(partial_solutions.iter().map(|solution| *solution.commitment()))?;
-        ensure!(challenges.len() == partial_solutions.len() + 1, "Invalid number of challenge points");
+        // Accumulate the partial solutions.
+        Self::accumulate_partial_solutions(pk, epoch_challenge, prover_polynomials, partial_solutions)
+    }
 
-        // Pop the last challenge as the accumulator challenge point.
-        let accumulator_point = match challenges.pop() {
-            Some(point) => point,
-            None => bail!("Missing the accumulator challenge point"),
+    /// Returns a coinbase solution that merges the partial solutions of the given coinbase solutions,
+    /// for the given epoch challenge.
+    ///
+    /// The merged solution is opened at a new accumulator point, derived from all of the puzzle commitments.
+    /// This method errors if either coinbase solution is not valid for the epoch challenge.
+    pub fn merge(
+        &self,
+        epoch_challenge: &EpochChallenge<N>,
+        first: &CoinbaseSolution<N>,
+        second: &CoinbaseSolution<N>,
+    ) -> Result<CoinbaseSolution<N>> {
+        // Retrieve the coinbase proving key.
+        let pk = match self {
+            Self::Prover(coinbase_proving_key) => coinbase_proving_key,
+            Self::Verifier(_) => bail!("Cannot merge coinbase solutions with a verifier"),
         };
 
-        // Accumulate the prover polynomial.
-        let zero = DensePolynomial::zero;
-        let accumulated_prover_polynomial = cfg_zip_fold!(
-            cfg_into_iter!(prover_polynomials),
-            challenges,
-            zero,
-            |mut accumulator, (mut prover_polynomial, challenge)| {
-                prover_polynomial *= challenge;
-                accumulator += &prover_polynomial;
-                accumulator
-            },
-            DensePolynomial<_>
+        // Ensure the epoch polynomial is supported by the proving key.
+        epoch_challenge.ensure_product_domain(&pk.product_domain)?;
+
+        // Ensure the number of partial solutions does not exceed `MAX_PROVER_SOLUTIONS`.
+        let num_solutions = first.len().saturating_add(second.len());
+        ensure!(
+            num_solutions <= N::MAX_PROVER_SOLUTIONS,
+            "Cannot merge beyond {} prover solutions, found {num_solutions}.",
+            N::MAX_PROVER_SOLUTIONS
         );
-        let product_eval_at_challenge_point = accumulated_prover_polynomial.evaluate(accumulator_point)
-            * epoch_challenge.epoch_polynomial().evaluate(accumulator_point);
 
-        // Compute the accumulator polynomial.
-        let product_evals = {
-            let accumulated_polynomial_evaluations =
-                pk.product_domain.in_order_fft_with_pc(&accumulated_prover_polynomial.coeffs, &pk.fft_precomputation);
-            pk.product_domain.mul_polynomials_in_evaluation_domain(
-                accumulated_polynomial_evaluations,
-                &epoch_challenge.epoch_polynomial_evaluations().evaluations,
-            )
-        };
+        // Ensure both coinbase solutions are valid for the epoch challenge.
+        for solution in [first, second] {
+            ensure!(
+                self.verify(solution, epoch_challenge, 0, 0)?,
+                "Cannot merge a coinbase solution that is invalid for epoch {}",
+                epoch_challenge.epoch_number()
+            );
+        }
 
-        // Compute the coinbase proof.
-        let proof = KZG10::open_lagrange(
-            &pk.lagrange_basis(),
-            pk.product_domain_elements(),
-            &product_evals,
-            accumulator_point,
-            product_eval_at_challenge_point,
-        )?;
+        // Concatenate the partial solutions.
+        let partial_solutions: Vec<_> =
+            first.partial_solutions().iter().chain(second.partial_solutions()).copied().collect();
+        ensure!(!has_duplicates(&partial_solutions), "Cannot merge duplicate partial solutions");
 
-        // Ensure the coinbase proof is non-hiding.
-        if proof.is_hiding() {
-            bail!("The coinbase proof must be non-hiding");
-        }
+        // Compute the prover polynomials.
+        let prover_polynomials = cfg_iter!(partial_solutions)
+            .map(|solution| solution.to_prover_polynomial(epoch_challenge))
+            .collect::<Result<Vec<_>>>()?;
 
-        // Return the accumulated proof.
-        Ok(CoinbaseSolution::new(partial_solutions, proof))
+        // Accumulate the partial solutions.
+        Self::accumulate_partial_solutions(pk, epoch_challenge, prover_polynomials, partial_solutions)
     }
 
     /// Returns `true` if the coinbase solution is valid.
@@ -329,11 +366,8 @@ impl<N: Network> CoinbasePuzzle<N> {
         accumulator_evaluation *= &epoch_challenge.epoch_polynomial().evaluate(accumulator_point);
 
         // Compute the accumulator commitment.
-        let commitments: Vec<_> =
-            cfg_iter!(coinbase_solution.partial_solutions()).map(|solution| solution.commitment().0).collect();
-        let fs_challenges = challenge_points.into_iter().map(|f| f.to_bigint()).collect::<Vec<_>>();
         let accumulator_commitment =
-            KZGCommitment::<N::PairingCurve>(VariableBase::msm(&commitments, &fs_challenges).into());
+            Self::combine_commitments(coinbase_solution.partial_solutions(), &challenge_points)?;
 
         // Retrieve the coinbase verifying key.
         let coinbase_verifying_key = match self {
@@ -385,6 +419,103 @@ impl<N: Network> CoinbasePuzzle<N> {
         Ok(product_domain)
     }
 
+    /// Returns the accumulator commitment, as the linear combination of the partial solution commitments
+    /// with the given challenge points. This is the commitment that the coinbase proof is checked against.
+    pub fn combine_commitments(
+        partial_solutions: &[PartialSolution<N>],
+        challenge_points: &[<N::PairingCurve as PairingEngine>::Fr],
+    ) -> Result<KZGCommitment<N::PairingCurve>> {
+        ensure!(
+            partial_solutions.len() == challenge_points.len(),
+            "Mismatching number of partial solutions ({}) and challenge points ({})",
+            partial_solutions.len(),
+            challenge_points.len()
+        );
+        let commitments: Vec<_> = cfg_iter!(partial_solutions).map(|solution| solution.commitment().0).collect();
+        let fs_challenges = challenge_points.iter().map(|f| f.to_bigint()).collect::<Vec<_>>();
+        Ok(KZGCommitment::<N::PairingCurve>(VariableBase::msm(&commitments, &fs_challenges).into()))
+    }
+
+    /// Returns the commitment to the product of the prover and epoch polynomials, and the product evaluations.
+    fn commit_to_product(
+        pk: &CoinbaseProvingKey<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        polynomial: &DensePolynomial<<N::PairingCurve as PairingEngine>::Fr>,
+    ) -> Result<(KZGCommitment<N::PairingCurve>, Vec<<N::PairingCurve as PairingEngine>::Fr>)> {
+        let product_evaluations = {
+            let polynomial_evaluations = pk.product_domain.in_order_fft_with_pc(polynomial, &pk.fft_precomputation);
+            let product_evaluations = pk.product_domain.mul_polynomials_in_evaluation_domain(
+                polynomial_evaluations,
+                &epoch_challenge.epoch_polynomial_evaluations().evaluations,
+            );
+            product_evaluations
+        };
+        let (commitment, _rand) =
+            KZG10::commit_lagrange(&pk.lagrange_basis(), &product_evaluations, None, &Default::default(), None)?;
+        Ok((commitment, product_evaluations))
+    }
+
+    /// Returns a coinbase solution that accumulates the given partial solutions and their prover polynomials.
+    fn accumulate_partial_solutions(
+        pk: &CoinbaseProvingKey<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        prover_polynomials: Vec<DensePolynomial<<N::PairingCurve as PairingEngine>::Fr>>,
+        partial_solutions: Vec<PartialSolution<N>>,
+    ) -> Result<CoinbaseSolution<N>> {
+        // Compute the challenge points.
+        let mut challenges = hash_commitments(partial_solutions.iter().map(|solution| *solution.commitment()))?;
+        ensure!(challenges.len() == partial_solutions.len() + 1, "Invalid number of challenge points");
+
+        // Pop the last challenge as the accumulator challenge point.
+        let accumulator_point = match challenges.pop() {
+            Some(point) => point,
+            None => bail!("Missing the accumulator challenge point"),
+        };
+
+        // Accumulate the prover polynomial.
+        let zero = DensePolynomial::zero;
+        let accumulated_prover_polynomial = cfg_zip_fold!(
+            cfg_into_iter!(prover_polynomials),
+            challenges,
+            zero,
+            |mut accumulator, (mut prover_polynomial, challenge)| {
+                prover_polynomial *= challenge;
+                accumulator += &prover_polynomial;
+                accumulator
+            },
+            DensePolynomial<_>
+        );
+        let product_eval_at_challenge_point = accumulated_prover_polynomial.evaluate(accumulator_point)
+            * epoch_challenge.epoch_polynomial().evaluate(accumulator_point);
+
+        // Compute the accumulator polynomial.
+        let product_evals = {
+            let accumulated_polynomial_evaluations =
+                pk.product_domain.in_order_fft_with_pc(&accumulated_prover_polynomial.coeffs, &pk.fft_precomputation);
+            pk.product_domain.mul_polynomials_in_evaluation_domain(
+                accumulated_polynomial_evaluations,
+                &epoch_challenge.epoch_polynomial_evaluations().evaluations,
+            )
+        };
+
+        // Compute the coinbase proof.
+        let proof = KZG10::open_lagrange(
+            &pk.lagrange_basis(),
+            pk.product_domain_elements(),
+            &product_evals,
+            accumulator_point,
+            product_eval_at_challenge_point,
+        )?;
+
+        // Ensure the coinbase proof is non-hiding.
+        if proof.is_hiding() {
+            bail!("The coinbase proof must be non-hiding");
+        }
+
+        // Return the accumulated proof.
+        Ok(CoinbaseSolution::new(partial_solutions, proof))
+    }
+
     /// Returns the prover polynomial for the coinbase puzzle.
     fn prover_polynomial(
         epoch_challenge: &EpochChallenge<N>,
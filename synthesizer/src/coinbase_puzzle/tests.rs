@@ -16,6 +16,8 @@
 
 use super::*;
 use console::{account::*, network::Testnet3};
+use snarkvm_curves::{AffineCurve, ProjectiveCurve};
+use snarkvm_fields::One;
 use snarkvm_utilities::Uniform;
 
 use rand::RngCore;
@@ -85,6 +87,301 @@ fn test_prover_solution_minimum_target() {
     }
 }
 
+#[test]
+fn test_prove_rejects_oversized_epoch_challenge() {
+    let mut rng = TestRng::default();
+
+    let max_config = PuzzleConfig { degree: 1 << 7 };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+
+    // Trim the puzzle to support epoch polynomials of degree at most 31.
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree: 31 }).unwrap();
+
+    let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+    let address = Address::try_from(private_key).unwrap();
+
+    // Ensure an epoch challenge within the supported degree is accepted.
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), 31).unwrap();
+    assert!(puzzle.prove(&epoch_challenge, address, u64::rand(&mut rng), None).is_ok());
+
+    // Ensure an oversized epoch challenge is rejected, instead of panicking.
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), 63).unwrap();
+    let error = puzzle.prove(&epoch_challenge, address, u64::rand(&mut rng), None).unwrap_err();
+    assert_eq!("The epoch polynomial degree (63) exceeds the maximum degree (31)", error.to_string());
+}
+
+#[test]
+fn test_prove_rejects_undersized_epoch_challenge() {
+    let mut rng = TestRng::default();
+
+    let max_config = PuzzleConfig { degree: 1 << 7 };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+
+    // Trim the puzzle to a product domain of size 64.
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree: 31 }).unwrap();
+
+    let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+    let address = Address::try_from(private_key).unwrap();
+
+    // Ensure an epoch challenge over a smaller product domain is rejected, instead of panicking.
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), 15).unwrap();
+    let expected = "The epoch polynomial domain size (32) does not match the product domain size (64)";
+    let error = puzzle.prove(&epoch_challenge, address, u64::rand(&mut rng), None).unwrap_err();
+    assert_eq!(expected, error.to_string());
+    let error = puzzle.commit(&epoch_challenge, address, u64::rand(&mut rng)).unwrap_err();
+    assert_eq!(expected, error.to_string());
+
+    // Ensure prover solutions cannot be accumulated with an epoch challenge over a smaller product domain.
+    let valid_epoch_challenge = EpochChallenge::new(epoch_challenge.epoch_number(), Default::default(), 31).unwrap();
+    let solution = puzzle.prove(&valid_epoch_challenge, address, u64::rand(&mut rng), None).unwrap();
+    let error = puzzle.accumulate_unchecked(&epoch_challenge, &[solution]).unwrap_err();
+    assert_eq!(expected, error.to_string());
+}
+
+#[test]
+fn test_prove_for_difficulty() {
+    let mut rng = TestRng::default();
+
+    let max_config = PuzzleConfig { degree: 1 << 7 };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree: 127 }).unwrap();
+    let max_degree = puzzle.coinbase_proving_key().unwrap().max_degree();
+
+    let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+    let address = Address::try_from(private_key).unwrap();
+
+    for difficulty in [0, 3, 1 << 10, u64::MAX] {
+        let epoch_challenge =
+            EpochChallenge::new_for_difficulty(rng.next_u32(), Default::default(), difficulty, max_degree).unwrap();
+
+        // Ensure the epoch challenge is proven with the coinbase puzzle for the difficulty.
+        let difficulty_puzzle = puzzle.trim_for_difficulty(difficulty).unwrap();
+        let solutions = (0..2)
+            .map(|_| difficulty_puzzle.prove(&epoch_challenge, address, u64::rand(&mut rng), None).unwrap())
+            .collect::<Vec<_>>();
+        for solution in &solutions {
+            assert!(solution.verify(puzzle.coinbase_verifying_key(), &epoch_challenge, 0).unwrap());
+        }
+        let coinbase_solution = difficulty_puzzle.accumulate_unchecked(&epoch_challenge, &solutions).unwrap();
+        assert!(puzzle.verify(&coinbase_solution, &epoch_challenge, 0u64, 0u64).unwrap());
+
+        // Ensure the full coinbase puzzle rejects an epoch challenge of a lower degree, instead of panicking.
+        let result = puzzle.prove(&epoch_challenge, address, u64::rand(&mut rng), None);
+        assert_eq!(epoch_challenge.degree() as usize == max_degree, result.is_ok());
+    }
+
+    // Ensure the coinbase puzzle cannot be trimmed with a verifier.
+    let verifier = CoinbasePuzzle::<Testnet3>::Verifier(Arc::new(puzzle.coinbase_verifying_key().clone()));
+    assert!(verifier.trim_for_difficulty(0).is_err());
+}
+
+#[test]
+fn test_solution_nonce_matches_commitment() {
+    let mut rng = TestRng::default();
+
+    let max_config = PuzzleConfig { degree: 1 << 7 };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree: 31 }).unwrap();
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), 31).unwrap();
+
+    let solutions = (0..4)
+        .map(|_| {
+            let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+            let address = Address::try_from(private_key).unwrap();
+            let nonce = u64::rand(&mut rng);
+            let solution = puzzle.prove(&epoch_challenge, address, nonce, None).unwrap();
+
+            // Ensure the stored nonce is the nonce committed to in the commitment.
+            assert_eq!(nonce, solution.nonce());
+            assert_eq!(solution.commitment(), puzzle.commit(&epoch_challenge, address, nonce).unwrap());
+            assert!(solution.matches_commitment(&puzzle, &epoch_challenge).unwrap());
+
+            // Ensure a solution reporting a different nonce is rejected.
+            let mismatched = PartialSolution::new(address, nonce.wrapping_add(1), solution.commitment());
+            assert!(!mismatched.matches_commitment(&puzzle, &epoch_challenge).unwrap());
+            solution
+        })
+        .collect::<Vec<_>>();
+
+    // Ensure the accumulated solution preserves the committed nonces.
+    let coinbase_solution = puzzle.accumulate_unchecked(&epoch_challenge, &solutions).unwrap();
+    assert!(coinbase_solution.matches_commitments(&puzzle, &epoch_challenge).unwrap());
+}
+
+#[test]
+fn test_merge_coinbase_solutions() {
+    let mut rng = TestRng::default();
+
+    let max_config = PuzzleConfig { degree: 1 << 7 };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree: 31 }).unwrap();
+
+    // Samples prover solutions for the given epoch challenge.
+    let mut sample_solutions = |epoch_challenge: &EpochChallenge<Testnet3>, num_solutions: usize| {
+        (0..num_solutions)
+            .map(|_| {
+                let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+                let address = Address::try_from(private_key).unwrap();
+                puzzle.prove(epoch_challenge, address, u64::rand(&mut rng), None).unwrap()
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let epoch_challenge = EpochChallenge::new(1, Default::default(), 31).unwrap();
+    let solutions = sample_solutions(&epoch_challenge, 4);
+
+    // Ensure merging two valid halves yields a valid coinbase solution.
+    let first = puzzle.accumulate_unchecked(&epoch_challenge, &solutions[..2]).unwrap();
+    let second = puzzle.accumulate_unchecked(&epoch_challenge, &solutions[2..]).unwrap();
+    let merged = puzzle.merge(&epoch_challenge, &first, &second).unwrap();
+    assert_eq!(4, merged.len());
+    assert!(puzzle.verify(&merged, &epoch_challenge, 0u64, 0u64).unwrap());
+    assert_eq!(puzzle.accumulate_unchecked(&epoch_challenge, &solutions).unwrap(), merged);
+
+    // Ensure merging a coinbase solution with itself fails.
+    assert!(puzzle.merge(&epoch_challenge, &first, &first).is_err());
+
+    // Ensure merging coinbase solutions for different epochs fails.
+    let other_epoch_challenge = EpochChallenge::new(2, Default::default(), 31).unwrap();
+    let other_solutions = sample_solutions(&other_epoch_challenge, 2);
+    let other = puzzle.accumulate_unchecked(&other_epoch_challenge, &other_solutions).unwrap();
+    assert!(puzzle.merge(&epoch_challenge, &first, &other).is_err());
+    assert!(puzzle.merge(&other_epoch_challenge, &first, &other).is_err());
+
+    // Ensure merging with an epoch polynomial beyond the degree bound fails.
+    let oversized_epoch_challenge = EpochChallenge::new(1, Default::default(), 63).unwrap();
+    assert!(puzzle.merge(&oversized_epoch_challenge, &first, &second).is_err());
+}
+
+#[test]
+fn test_accumulator() {
+    let mut rng = TestRng::default();
+
+    let max_config = PuzzleConfig { degree: 1 << 7 };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree: 31 }).unwrap();
+
+    // Samples prover solutions for the given epoch challenge.
+    let mut sample_solutions = |epoch_challenge: &EpochChallenge<Testnet3>, num_solutions: usize| {
+        (0..num_solutions)
+            .map(|_| {
+                let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+                let address = Address::try_from(private_key).unwrap();
+                puzzle.prove(epoch_challenge, address, u64::rand(&mut rng), None).unwrap()
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let epoch_challenge = EpochChallenge::new(1, Default::default(), 31).unwrap();
+    let solutions = sample_solutions(&epoch_challenge, 5);
+
+    // Ensure an empty accumulator cannot be finalized.
+    let mut accumulator = Accumulator::new(puzzle.clone());
+    assert!(accumulator.finalize(&epoch_challenge).is_err());
+
+    // Ensure accumulating the prover solutions yields a valid coinbase solution.
+    for (i, solution) in solutions.iter().enumerate() {
+        accumulator.push(solution, &epoch_challenge).unwrap();
+        assert_eq!(i + 1, accumulator.len());
+    }
+    assert_eq!(Some(1), accumulator.epoch_number());
+    let coinbase_solution = accumulator.finalize(&epoch_challenge).unwrap();
+    assert_eq!(5, coinbase_solution.len());
+    assert!(puzzle.verify(&coinbase_solution, &epoch_challenge, 0u64, 0u64).unwrap());
+    assert_eq!(puzzle.accumulate_unchecked(&epoch_challenge, &solutions).unwrap(), coinbase_solution);
+
+    // Ensure a duplicate prover solution is rejected.
+    assert!(accumulator.push(&solutions[0], &epoch_challenge).is_err());
+    assert_eq!(5, accumulator.len());
+
+    // Ensure a prover solution for a different epoch is rejected.
+    let other_epoch_challenge = EpochChallenge::new(2, Default::default(), 31).unwrap();
+    let other_solutions = sample_solutions(&other_epoch_challenge, 1);
+    let error = accumulator.push(&other_solutions[0], &other_epoch_challenge).unwrap_err();
+    assert_eq!("Cannot accumulate a prover solution for epoch 2 into an accumulator for epoch 1", error.to_string());
+    assert!(accumulator.finalize(&other_epoch_challenge).is_err());
+
+    // Ensure a prover solution that is invalid for the epoch challenge is rejected.
+    let mut accumulator = Accumulator::new(puzzle.clone());
+    assert!(accumulator.push(&other_solutions[0], &epoch_challenge).is_err());
+    assert!(accumulator.is_empty());
+    assert_eq!(None, accumulator.epoch_number());
+}
+
+#[test]
+fn test_combine_commitments() {
+    type Fr = <<Testnet3 as Environment>::PairingCurve as PairingEngine>::Fr;
+    type G1Projective = <<Testnet3 as Environment>::PairingCurve as PairingEngine>::G1Projective;
+
+    let mut rng = TestRng::default();
+
+    // Samples a partial solution with a random commitment.
+    let mut sample_partial_solution = || {
+        let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+        let address = Address::try_from(private_key).unwrap();
+        let commitment = KZGCommitment(G1Projective::rand(&mut rng).to_affine());
+        (PartialSolution::new(address, u64::rand(&mut rng), commitment), Fr::rand(&mut rng))
+    };
+
+    // Ensure combining a single solution with a unit challenge point is the identity.
+    let (solution, _) = sample_partial_solution();
+    let combined = CoinbasePuzzle::<Testnet3>::combine_commitments(&[solution], &[Fr::one()]).unwrap();
+    assert_eq!(solution.commitment(), combined.into());
+
+    // Ensure the combination matches an independent computation.
+    for num_solutions in 1..10 {
+        let (solutions, challenge_points): (Vec<_>, Vec<_>) =
+            (0..num_solutions).map(|_| sample_partial_solution()).unzip();
+        let combined = CoinbasePuzzle::<Testnet3>::combine_commitments(&solutions, &challenge_points).unwrap();
+
+        let expected = solutions.iter().zip_eq(&challenge_points).fold(
+            G1Projective::zero(),
+            |accumulator, (solution, challenge_point)| {
+                accumulator + solution.commitment().0.to_projective() * *challenge_point
+            },
+        );
+        assert_eq!(expected.to_affine(), combined.0);
+    }
+
+    // Ensure a mismatched number of challenge points is rejected.
+    let (solution, challenge_point) = sample_partial_solution();
+    assert!(CoinbasePuzzle::<Testnet3>::combine_commitments(&[solution], &[challenge_point, challenge_point]).is_err());
+}
+
+#[test]
+fn test_trim_proving_key() {
+    let mut rng = TestRng::default();
+
+    let srs = CoinbasePuzzle::<Testnet3>::setup(PuzzleConfig { degree: 1 << 7 }).unwrap();
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree: 127 }).unwrap();
+    let proving_key = puzzle.coinbase_proving_key().unwrap();
+    assert_eq!(127, proving_key.max_degree());
+
+    let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+    let address = Address::try_from(private_key).unwrap();
+
+    for degree in [31, 63, 127] {
+        let trimmed = proving_key.trim(degree).unwrap();
+        assert_eq!(degree as usize, trimmed.max_degree());
+
+        // Ensure the trimmed proving key matches the proving key derived from the SRS.
+        let expected = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree }).unwrap();
+        let expected = expected.coinbase_proving_key().unwrap();
+        assert_eq!(expected.lagrange_basis_at_beta_g, trimmed.lagrange_basis_at_beta_g);
+        assert_eq!(expected.product_domain_elements, trimmed.product_domain_elements);
+
+        // Ensure a solution from the trimmed proving key verifies with the original verifying key.
+        let trimmed_puzzle = CoinbasePuzzle::Prover(Arc::new(trimmed));
+        let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+        let solution = trimmed_puzzle.prove(&epoch_challenge, address, u64::rand(&mut rng), None).unwrap();
+        assert!(solution.verify(puzzle.coinbase_verifying_key(), &epoch_challenge, 0).unwrap());
+    }
+
+    // Ensure the proving key cannot be trimmed beyond its supported degree.
+    let error = proving_key.trim(128).unwrap_err();
+    assert_eq!("The proving key supports a maximum degree of 127, found 128", error.to_string());
+}
+
 #[test]
 fn test_edge_case_for_degree() {
     let mut rng = rand::thread_rng();
@@ -108,3 +405,58 @@ fn test_edge_case_for_degree() {
     let coinbase_solution = puzzle.accumulate_unchecked(&epoch_challenge, &[prover_solution]).unwrap();
     assert!(puzzle.verify(&coinbase_solution, &epoch_challenge, 0u64, 0u64).unwrap());
 }
+
+#[test]
+fn test_prover_solution_verify_batch() {
+    let mut rng = TestRng::default();
+
+    let max_degree = 1 << 15;
+    let max_config = PuzzleConfig { degree: max_degree };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+
+    for log_degree in 5..10 {
+        let degree = (1 << log_degree) - 1;
+        let config = PuzzleConfig { degree };
+        let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, config).unwrap();
+        let verifying_key = puzzle.coinbase_verifying_key();
+        let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+
+        for batch_size in 1..10 {
+            let solutions = (0..batch_size)
+                .map(|_| {
+                    let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+                    let address = Address::try_from(private_key).unwrap();
+                    let nonce = u64::rand(&mut rng);
+                    puzzle.prove(&epoch_challenge, address, nonce, None).unwrap()
+                })
+                .collect::<Vec<_>>();
+
+            // Ensure a batch of valid solutions is accepted.
+            let results = ProverSolution::verify_batch(&solutions, verifying_key, &epoch_challenge, 0u64, &mut rng);
+            assert_eq!(vec![true; batch_size], results.unwrap());
+
+            // Invalidate a random subset of the solutions, by swapping in the proof of another solution.
+            let other_solution = puzzle.prove(&epoch_challenge, solutions[0].address(), rng.gen(), None).unwrap();
+            let tampered_solutions = solutions
+                .iter()
+                .map(|solution| match rng.gen_bool(0.5) {
+                    true => {
+                        let partial_solution =
+                            PartialSolution::new(solution.address(), solution.nonce(), solution.commitment());
+                        ProverSolution::new(partial_solution, *other_solution.proof())
+                    }
+                    false => *solution,
+                })
+                .collect::<Vec<_>>();
+
+            // Ensure the batch result flags exactly the tampered solutions.
+            let results =
+                ProverSolution::verify_batch(&tampered_solutions, verifying_key, &epoch_challenge, 0u64, &mut rng)
+                    .unwrap();
+            for ((result, solution), tampered_solution) in results.iter().zip(&solutions).zip(&tampered_solutions) {
+                assert_eq!(solution == tampered_solution, *result);
+                assert_eq!(tampered_solution.verify(verifying_key, &epoch_challenge, 0u64).unwrap(), *result);
+            }
+        }
+    }
+}
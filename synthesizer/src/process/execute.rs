@@ -223,13 +223,14 @@ impl<N: Network> Process<N> {
         Ok(())
     }
 
-    /// Finalizes the execution.
+    /// Finalizes the execution, for the block at the given height.
     /// This method assumes the given execution **is valid**.
     #[inline]
     pub fn finalize_execution<P: ProgramStorage<N>>(
         &self,
         store: &ProgramStore<N, P>,
         execution: &Execution<N>,
+        block_height: u32,
     ) -> Result<()> {
         let timer = timer!("Program::finalize_execution");
 
@@ -274,7 +275,8 @@ impl<N: Network> Process<N> {
                 };
 
                 // Initialize the registers.
-                let mut registers = FinalizeRegisters::<N>::new(stack.get_finalize_types(finalize.name())?.clone());
+                let mut registers =
+                    FinalizeRegisters::<N>::new(block_height, stack.get_finalize_types(finalize.name())?.clone());
 
                 // Store the inputs.
                 finalize.inputs().iter().map(|i| i.register()).zip_eq(inputs).try_for_each(|(register, input)| {
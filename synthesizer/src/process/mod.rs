@@ -1239,7 +1239,7 @@ finalize compute:
         process.verify_execution::<true>(&execution).unwrap();
 
         // Now, finalize the execution.
-        process.finalize_execution(&store, &execution).unwrap();
+        process.finalize_execution(&store, &execution, 1).unwrap();
 
         // Check that the account balance is now 8.
         let candidate =
@@ -1339,7 +1339,7 @@ finalize compute:
         process.verify_execution::<true>(&execution).unwrap();
 
         // Now, finalize the execution.
-        process.finalize_execution(&store, &execution).unwrap();
+        process.finalize_execution(&store, &execution, 1).unwrap();
 
         // Check that the account balance is now 0.
         let candidate =
@@ -1457,7 +1457,7 @@ finalize mint_public:
         process.verify_execution::<true>(&execution).unwrap();
 
         // Now, finalize the execution.
-        process.finalize_execution(&store, &execution).unwrap();
+        process.finalize_execution(&store, &execution, 1).unwrap();
 
         // Check the account balance.
         let candidate =
@@ -1596,7 +1596,7 @@ function mint:
         process.verify_execution::<true>(&execution).unwrap();
 
         // Now, finalize the execution.
-        process.finalize_execution(&store, &execution).unwrap();
+        process.finalize_execution(&store, &execution, 1).unwrap();
 
         // Check the account balance.
         let candidate =
@@ -1698,7 +1698,7 @@ finalize compute:
         process.verify_execution::<true>(&execution).unwrap();
 
         // Now, finalize the execution.
-        process.finalize_execution(&store, &execution).unwrap();
+        process.finalize_execution(&store, &execution, 1).unwrap();
 
         // Check that the account balance is now 8.
         let candidate =
@@ -77,6 +77,8 @@ impl<N: Network> Stack<N> {
                     }
                     // If the operand is the caller, retrieve the caller from the registers.
                     Operand::Caller => Ok(Value::Plaintext(Plaintext::from(Literal::Address(registers.caller()?)))),
+                    // If the operand is the block height, throw an error.
+                    Operand::BlockHeight => bail!(BLOCK_HEIGHT_OUTSIDE_FINALIZE),
                 }
             })
             .collect();
@@ -175,6 +177,8 @@ impl<N: Network> Stack<N> {
                     }
                     // If the operand is the caller, retrieve the caller from the registers.
                     Operand::Caller => Ok(Value::Plaintext(Plaintext::from(Literal::Address(registers.caller()?)))),
+                    // If the operand is the block height, throw an error.
+                    Operand::BlockHeight => bail!(BLOCK_HEIGHT_OUTSIDE_FINALIZE),
                 }
             })
             .collect::<Result<Vec<_>>>()?;
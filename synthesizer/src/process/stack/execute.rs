@@ -106,6 +106,8 @@ impl<N: Network> Stack<N> {
                     Operand::Caller => Ok(circuit::Value::Plaintext(circuit::Plaintext::from(
                         circuit::Literal::Address(registers.caller_circuit()?),
                     ))),
+                    // If the operand is the block height, throw an error.
+                    Operand::BlockHeight => bail!(BLOCK_HEIGHT_OUTSIDE_FINALIZE),
                 }
             })
             .collect();
@@ -264,6 +266,8 @@ impl<N: Network> Stack<N> {
                     Operand::Caller => Ok(circuit::Value::Plaintext(circuit::Plaintext::from(
                         circuit::Literal::Address(registers.caller_circuit()?),
                     ))),
+                    // If the operand is the block height, throw an error.
+                    Operand::BlockHeight => bail!(BLOCK_HEIGHT_OUTSIDE_FINALIZE),
                 }
             })
             .collect::<Result<Vec<_>>>()?;
@@ -36,6 +36,10 @@ impl<N: Network> Load<N> for FinalizeRegisters<N> {
             }
             // If the operand is the caller, load the value of the caller.
             Operand::Caller => bail!("Forbidden operation: Cannot use 'self.caller' in 'finalize'"),
+            // If the operand is the block height, load the block height.
+            Operand::BlockHeight => {
+                return Ok(Value::Plaintext(Plaintext::from(Literal::U32(U32::new(self.block_height)))));
+            }
         };
 
         // Retrieve the plaintext value.
@@ -61,3 +65,47 @@ impl<N: Network> Load<N> for FinalizeRegisters<N> {
         Ok(Value::Plaintext(plaintext_value))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{process::test_helpers::sample_process, Program};
+    use console::{network::Testnet3, program::Identifier};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_load_block_height() -> Result<()> {
+        // Initialize a program that uses the block height in finalize.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function run:
+    input r0 as u32.public;
+    finalize r0;
+
+finalize run:
+    input r0 as u32.public;
+    add r0 block.height into r1;",
+        )?;
+
+        // Initialize the stack.
+        let process = sample_process(&program);
+        let stack = process.get_stack(program.id())?;
+
+        for block_height in [0, 1, 12345, u32::MAX] {
+            // Initialize the registers.
+            let registers = FinalizeRegisters::<CurrentNetwork>::new(
+                block_height,
+                stack.get_finalize_types(&Identifier::from_str("run")?)?.clone(),
+            );
+            assert_eq!(block_height, registers.block_height());
+
+            // Ensure the block height is loaded.
+            let expected = Value::Plaintext(Plaintext::from(Literal::U32(U32::new(block_height))));
+            assert_eq!(expected, registers.load(stack, &Operand::BlockHeight)?);
+        }
+        Ok(())
+    }
+}
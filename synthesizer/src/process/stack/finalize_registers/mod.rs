@@ -21,12 +21,15 @@ use crate::{FinalizeTypes, Load, Operand, Stack, Store};
 use console::{
     network::prelude::*,
     program::{Literal, Plaintext, Register, Value},
+    types::U32,
 };
 
 use indexmap::IndexMap;
 
 #[derive(Clone)]
 pub struct FinalizeRegisters<N: Network> {
+    /// The block height of the block being finalized.
+    block_height: u32,
     /// The mapping of all registers to their defined types.
     finalize_types: FinalizeTypes<N>,
     /// The mapping of assigned registers to their values.
@@ -34,9 +37,15 @@ pub struct FinalizeRegisters<N: Network> {
 }
 
 impl<N: Network> FinalizeRegisters<N> {
-    /// Initializes a new set of registers, given the finalize types.
+    /// Initializes a new set of registers, given the block height and finalize types.
     #[inline]
-    pub fn new(finalize_types: FinalizeTypes<N>) -> Self {
-        Self { finalize_types, registers: IndexMap::new() }
+    pub fn new(block_height: u32, finalize_types: FinalizeTypes<N>) -> Self {
+        Self { block_height, finalize_types, registers: IndexMap::new() }
+    }
+
+    /// Returns the block height of the block being finalized.
+    #[inline]
+    pub const fn block_height(&self) -> u32 {
+        self.block_height
     }
 }
@@ -80,6 +80,16 @@ impl<N: Network> FinalizeTypes<N> {
                         "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{caller_type}' in the operand '{operand}'.",
                     )
                 }
+                // Ensure the block height type (u32) matches the member type.
+                Operand::BlockHeight => {
+                    // Retrieve the block height type.
+                    let block_height_type = RegisterType::Plaintext(PlaintextType::Literal(LiteralType::U32));
+                    // Ensure the block height type matches the member type.
+                    ensure!(
+                        block_height_type == RegisterType::Plaintext(*member_type),
+                        "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{block_height_type}' in the operand '{operand}'.",
+                    )
+                }
             }
         }
         Ok(())
@@ -71,6 +71,7 @@ impl<N: Network> FinalizeTypes<N> {
             Operand::Register(register) => self.get_type(stack, register)?,
             Operand::ProgramID(_) => PlaintextType::Literal(LiteralType::Address),
             Operand::Caller => PlaintextType::Literal(LiteralType::Address),
+            Operand::BlockHeight => PlaintextType::Literal(LiteralType::U32),
         })
     }
 
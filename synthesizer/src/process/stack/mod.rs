@@ -51,6 +51,7 @@ mod execute;
 mod helpers;
 
 use crate::{
+    BLOCK_HEIGHT_OUTSIDE_FINALIZE,
     CallOperator,
     Certificate,
     Closure,
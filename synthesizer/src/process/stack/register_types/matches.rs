@@ -85,6 +85,8 @@ impl<N: Network> RegisterTypes<N> {
                         "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{caller_type}' in the operand '{operand}'.",
                     )
                 }
+                // If the operand is the block height, throw an error.
+                Operand::BlockHeight => bail!(BLOCK_HEIGHT_OUTSIDE_FINALIZE),
             }
         }
         Ok(())
@@ -139,6 +141,8 @@ impl<N: Network> RegisterTypes<N> {
                 bail!("Forbidden operation: Cannot cast a program ID ('{program_id}') as a record owner")
             }
             Operand::Caller => {}
+            // If the operand is the block height, throw an error.
+            Operand::BlockHeight => bail!(BLOCK_HEIGHT_OUTSIDE_FINALIZE),
         }
 
         // Ensure the operand types match the record entry types.
@@ -193,6 +197,8 @@ impl<N: Network> RegisterTypes<N> {
                                 "Record entry '{record_name}.{entry_name}' expects a '{plaintext_type}', but found '{caller_type}' in the operand '{operand}'.",
                             )
                         }
+                        // If the operand is the block height, throw an error.
+                        Operand::BlockHeight => bail!(BLOCK_HEIGHT_OUTSIDE_FINALIZE),
                     }
                 }
             }
@@ -17,7 +17,17 @@
 mod initialize;
 mod matches;
 
-use crate::{CallOperator, Closure, Function, Instruction, Opcode, Operand, Program, Stack};
+use crate::{
+    BLOCK_HEIGHT_OUTSIDE_FINALIZE,
+    CallOperator,
+    Closure,
+    Function,
+    Instruction,
+    Opcode,
+    Operand,
+    Program,
+    Stack,
+};
 use console::{
     network::prelude::*,
     program::{
@@ -72,6 +82,30 @@ impl<N: Network> RegisterTypes<N> {
         self.inputs.contains_key(&register.locator())
     }
 
+    /// Returns the locators of the input registers, in their declared order.
+    pub fn input_locators(&self) -> impl '_ + ExactSizeIterator<Item = u64> {
+        self.inputs.keys().copied()
+    }
+
+    /// Returns the given register, with a member path that addresses the entire value collapsed into a locator.
+    /// Genuine member accesses are returned unchanged, once their member path is resolved against the declared
+    /// type of the register. As each member of a struct or record is a distinct value from its parent,
+    /// only an empty member path addresses the entire value.
+    pub fn normalize(&self, stack: &Stack<N>, register: &Register<N>) -> Result<Register<N>> {
+        match register {
+            // If the member path is empty, ensure the register is declared, and collapse it into a locator.
+            Register::Member(locator, path) if path.is_empty() => {
+                ensure!(self.contains(register), "Register '{register}' does not exist");
+                Ok(Register::Locator(*locator))
+            }
+            // Otherwise, ensure the register (and its member path) resolves against its declared type.
+            _ => {
+                self.get_type(stack, register)?;
+                Ok(register.clone())
+            }
+        }
+    }
+
     /// Returns the register type of the given operand.
     pub fn get_type_from_operand(&self, stack: &Stack<N>, operand: &Operand<N>) -> Result<RegisterType<N>> {
         Ok(match operand {
@@ -79,6 +113,7 @@ impl<N: Network> RegisterTypes<N> {
             Operand::Register(register) => self.get_type(stack, register)?,
             Operand::ProgramID(_) => RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address)),
             Operand::Caller => RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address)),
+            Operand::BlockHeight => bail!(BLOCK_HEIGHT_OUTSIDE_FINALIZE),
         })
     }
 
@@ -170,3 +205,55 @@ impl<N: Network> RegisterTypes<N> {
         Ok(register_type)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::process::test_helpers::sample_process;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_normalize() -> Result<()> {
+        // Initialize the stack.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+struct point:
+    x as field;
+    y as field;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function run:
+    input r0 as point.private;
+    input r1 as token.record;
+    add r0.x r0.y into r2;
+    output r2 as field.private;",
+        )?;
+        let process = sample_process(&program);
+        let stack = process.get_stack(program.id())?;
+        let register_types = stack.get_register_types(&Identifier::from_str("run")?)?;
+
+        // Ensure a member path addressing the entire value collapses into a locator.
+        for locator in [0, 1, 2] {
+            let register = Register::Member(locator, vec![]);
+            assert_eq!(Register::Locator(locator), register_types.normalize(stack, &register)?);
+        }
+        // Ensure locators and genuine member accesses are preserved.
+        for string in ["r0", "r0.x", "r1", "r1.owner", "r1.amount", "r2"] {
+            let register = Register::from_str(string)?;
+            assert_eq!(register, register_types.normalize(stack, &register)?);
+        }
+        // Ensure undeclared registers and invalid member paths are rejected.
+        for register in [Register::Member(3, vec![]), Register::from_str("r3")?, Register::from_str("r0.z")?] {
+            assert!(register_types.normalize(stack, &register).is_err());
+        }
+        assert!(register_types.normalize(stack, &Register::from_str("r2.x")?).is_err());
+        Ok(())
+    }
+}
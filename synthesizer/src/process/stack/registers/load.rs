@@ -32,10 +32,12 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Load<N> for Registers<N, A> {
             Operand::Register(register) => register,
             // If the operand is the program ID, load the program address.
             Operand::ProgramID(program_id) => {
-                return Ok(Value::Plaintext(Plaintext::from(Literal::Address(program_id.to_address()?))));
+                return Ok(Value::Plaintext(Plaintext::from(Literal::Address(self.program_address(program_id)?))));
             }
             // If the operand is the caller, load the value of the caller.
             Operand::Caller => return Ok(Value::Plaintext(Plaintext::from(Literal::Address(self.caller()?)))),
+            // If the operand is the block height, throw an error.
+            Operand::BlockHeight => bail!(BLOCK_HEIGHT_OUTSIDE_FINALIZE),
         };
 
         // Retrieve the stack value.
@@ -50,27 +52,62 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Load<N> for Registers<N, A> {
             Register::Member(_, ref path) => {
                 match stack_value {
                     // Retrieve the plaintext member from the path.
-                    Value::Plaintext(plaintext) => Value::Plaintext(plaintext.find(path)?),
+                    Value::Plaintext(plaintext) => match plaintext.find(path) {
+                        Ok(plaintext) => Value::Plaintext(plaintext),
+                        Err(_) => bail!(self.missing_member_error(stack, register, stack_value)),
+                    },
                     // Retrieve the record entry from the path.
-                    Value::Record(record) => match record.find(path)? {
-                        Entry::Constant(plaintext) | Entry::Public(plaintext) | Entry::Private(plaintext) => {
+                    Value::Record(record) => match record.find(path) {
+                        Ok(Entry::Constant(plaintext) | Entry::Public(plaintext) | Entry::Private(plaintext)) => {
                             Value::Plaintext(plaintext)
                         }
+                        Err(_) => bail!(self.missing_member_error(stack, register, stack_value)),
                     },
                 }
             }
         };
 
-        // Retrieve the register type.
-        match self.register_types.get_type(stack, register) {
-            // Ensure the stack value matches the register type.
-            Ok(register_type) => stack.matches_register_type(&stack_value, &register_type)?,
-            // Ensure the register is defined.
-            Err(error) => bail!("Register '{register}' is not a member of the function: {error}"),
-        };
+        // Ensure the stack value matches the register type, unless it (or its whole register) was checked.
+        if !Self::is_checked(&self.console_checked.borrow(), register) {
+            // Retrieve the register type.
+            match self.get_register_type(stack, register) {
+                // Ensure the stack value matches the register type.
+                Ok(register_type) => stack.matches_register_type(&stack_value, &register_type)?,
+                // Ensure the register is defined.
+                Err(error) => bail!("Register '{register}' is not a member of the function: {error}"),
+            };
+            // Cache the register, as the value is not modified until the register is stored again.
+            self.console_checked.borrow_mut().insert(register.clone());
+        }
 
         Ok(stack_value)
     }
+
+    /// Loads the values of the given operands from the registers, in order.
+    ///
+    /// If the members of a register are referenced by more than one operand, the register is type-checked once
+    /// as a whole, so that each member is not looked up and checked individually.
+    ///
+    /// # Errors
+    /// This method will halt if any of the operands fails to load, indicating the index of the operand.
+    #[inline]
+    fn load_many(&self, stack: &Stack<N>, operands: &[Operand<N>]) -> Result<Vec<Value<N>>> {
+        // Type-check the registers whose members are shared across the operands.
+        let shared = Self::shared_locators(&self.console_checked.borrow(), operands);
+        for (index, locator) in shared {
+            self.load(stack, &Operand::Register(Register::Locator(locator)))
+                .map_err(|error| anyhow!("Failed to load operand {index} ('{}'): {error}", operands[index]))?;
+        }
+        // Load the operands.
+        operands
+            .iter()
+            .enumerate()
+            .map(|(index, operand)| {
+                self.load(stack, operand)
+                    .map_err(|error| anyhow!("Failed to load operand {index} ('{operand}'): {error}"))
+            })
+            .collect()
+    }
 }
 
 impl<N: Network, A: circuit::Aleo<Network = N>> LoadCircuit<N, A> for Registers<N, A> {
@@ -81,22 +118,92 @@ impl<N: Network, A: circuit::Aleo<Network = N>> LoadCircuit<N, A> for Registers<
     /// In the case of register members, this method will halt if the member is not found.
     #[inline]
     fn load_circuit(&self, stack: &Stack<N>, operand: &Operand<N>) -> Result<circuit::Value<A>> {
+        // Load the circuit value.
+        let circuit_value = self.load_circuit_unchecked(stack, operand)?;
+
+        // If the operand is a register, ensure the circuit value matches the register type.
+        if let Operand::Register(register) = operand {
+            // Ensure the circuit value matches the register type, unless it (or its whole register) was checked.
+            if !Self::is_checked(&self.circuit_checked.borrow(), register) {
+                // Retrieve the register type.
+                match self.get_register_type(stack, register) {
+                    // Ensure the stack value matches the register type.
+                    Ok(register_type) => {
+                        stack.matches_register_type(&circuit::Eject::eject_value(&circuit_value), &register_type)?
+                    }
+                    // Ensure the register is defined.
+                    Err(error) => bail!("Register '{register}' is not a member of the function: {error}"),
+                };
+                // Cache the register, as the value is not modified until the register is stored again.
+                self.circuit_checked.borrow_mut().insert(register.clone());
+            }
+        }
+
+        Ok(circuit_value)
+    }
+
+    /// Loads the values of the given operands from the registers, in order.
+    ///
+    /// If the members of a register are referenced by more than one operand, the register is type-checked once
+    /// as a whole, so that each member is not looked up and checked individually.
+    ///
+    /// # Errors
+    /// This method will halt if any of the operands fails to load, indicating the index of the operand.
+    #[inline]
+    fn load_many_circuit(&self, stack: &Stack<N>, operands: &[Operand<N>]) -> Result<Vec<circuit::Value<A>>> {
+        // Type-check the registers whose members are shared across the operands.
+        let shared = Self::shared_locators(&self.circuit_checked.borrow(), operands);
+        for (index, locator) in shared {
+            self.load_circuit(stack, &Operand::Register(Register::Locator(locator)))
+                .map_err(|error| anyhow!("Failed to load operand {index} ('{}'): {error}", operands[index]))?;
+        }
+        // Load the operands.
+        operands
+            .iter()
+            .enumerate()
+            .map(|(index, operand)| {
+                self.load_circuit(stack, operand)
+                    .map_err(|error| anyhow!("Failed to load operand {index} ('{operand}'): {error}"))
+            })
+            .collect()
+    }
+}
+
+impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
+    /// Loads the value of a given operand from the registers, without ensuring it matches the register type.
+    ///
+    /// This skips ejecting the circuit value to check it against the register type, which is costly for large values.
+    /// The caller must ensure the register type was already validated (e.g. by a prior `load_circuit` of the register,
+    /// or by checking the instruction during program verification), as a mismatched value is not detected here,
+    /// and would otherwise be synthesized into the circuit unchecked.
+    ///
+    /// # Errors
+    /// This method will halt if the register locator is not found.
+    /// In the case of register members, this method will halt if the member is not found.
+    #[inline]
+    pub fn load_circuit_unchecked(&self, stack: &Stack<N>, operand: &Operand<N>) -> Result<circuit::Value<A>> {
         use circuit::Inject;
 
         // Retrieve the register.
         let register = match operand {
             // If the operand is a literal, return the literal.
             Operand::Literal(literal) => {
-                return Ok(circuit::Value::Plaintext(circuit::Plaintext::from(circuit::Literal::constant(
-                    literal.clone(),
-                ))));
+                // Retrieve the constant for the literal, or inject it if it has not been loaded before.
+                // Note: The literal is keyed by its canonical key, so that identical literals reuse the same constant.
+                let constant = self
+                    .circuit_literals
+                    .borrow_mut()
+                    .entry(operand.canonical_key())
+                    .or_insert_with(|| circuit::Literal::constant(literal.clone()))
+                    .clone();
+                return Ok(circuit::Value::Plaintext(circuit::Plaintext::from(constant)));
             }
             // If the operand is a register, load the value from the register.
             Operand::Register(register) => register,
             // If the operand is the program ID, load the program address.
             Operand::ProgramID(program_id) => {
                 return Ok(circuit::Value::Plaintext(circuit::Plaintext::from(circuit::Literal::constant(
-                    Literal::Address(program_id.to_address()?),
+                    Literal::Address(self.program_address(program_id)?),
                 ))));
             }
             // If the operand is the caller, load the value of the caller.
@@ -105,6 +212,8 @@ impl<N: Network, A: circuit::Aleo<Network = N>> LoadCircuit<N, A> for Registers<
                     self.caller_circuit()?,
                 ))));
             }
+            // If the operand is the block height, throw an error.
+            Operand::BlockHeight => bail!(BLOCK_HEIGHT_OUTSIDE_FINALIZE),
         };
 
         // Retrieve the circuit value.
@@ -120,29 +229,744 @@ impl<N: Network, A: circuit::Aleo<Network = N>> LoadCircuit<N, A> for Registers<
                 // Inject the path.
                 let path = path.iter().map(|member| circuit::Identifier::constant(*member)).collect::<Vec<_>>();
 
-                match circuit_value {
+                // Retrieve the member from the path.
+                let member = match circuit_value {
                     // Retrieve the plaintext member from the path.
-                    circuit::Value::Plaintext(plaintext) => circuit::Value::Plaintext(plaintext.find(&path)?),
+                    circuit::Value::Plaintext(plaintext) => plaintext.find(&path).map(circuit::Value::Plaintext),
                     // Retrieve the record entry from the path.
-                    circuit::Value::Record(record) => match record.find(&path)? {
+                    circuit::Value::Record(record) => record.find(&path).map(|entry| match entry {
                         circuit::Entry::Constant(plaintext)
                         | circuit::Entry::Public(plaintext)
                         | circuit::Entry::Private(plaintext) => circuit::Value::Plaintext(plaintext),
-                    },
+                    }),
+                };
+                match member {
+                    Ok(member) => member,
+                    // Note: The circuit value is ejected, to report the same error as the console load.
+                    Err(_) => {
+                        bail!(self.missing_member_error(stack, register, &circuit::Eject::eject_value(circuit_value)))
+                    }
+                }
+            }
+        };
+
+        Ok(circuit_value)
+    }
+
+    /// Resolves and caches the register types of the given register operands, in a single pass.
+    /// Subsequent loads of these registers retrieve their register types from the cache.
+    ///
+    /// # Errors
+    /// This method will halt if a register operand is not defined in the function.
+    #[inline]
+    pub fn prefetch_types(&mut self, stack: &Stack<N>, operands: &[Operand<N>]) -> Result<()> {
+        for operand in operands {
+            if let Operand::Register(register) = operand {
+                // Skip the register, if its register type has already been prefetched.
+                if self.prefetched_types.contains_key(register) {
+                    continue;
+                }
+                // Retrieve the register type.
+                let register_type = self
+                    .get_register_type(stack, register)
+                    .map_err(|error| anyhow!("Register '{register}' is not a member of the function: {error}"))?;
+                // Cache the register type, as register types do not change over the function.
+                self.prefetched_types.insert(register.clone(), register_type);
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if the given register, or its whole register, has been type-checked.
+    /// Note: If the whole register matches its register type, then each of its members matches its member type.
+    fn is_checked(checked: &IndexSet<Register<N>>, register: &Register<N>) -> bool {
+        checked.contains(register) || checked.contains(&Register::Locator(register.locator()))
+    }
+
+    /// Returns the unchecked locators whose members are referenced by more than one operand, in order,
+    /// along with the index of the first operand that references each of them.
+    fn shared_locators(checked: &IndexSet<Register<N>>, operands: &[Operand<N>]) -> Vec<(usize, u64)> {
+        // Count the distinct member operands of each locator.
+        let mut members = IndexMap::<u64, (usize, IndexSet<&Register<N>>)>::new();
+        for (index, operand) in operands.iter().enumerate() {
+            if let Operand::Register(register @ Register::Member(locator, _)) = operand {
+                if !Self::is_checked(checked, register) {
+                    members.entry(*locator).or_insert_with(|| (index, IndexSet::new())).1.insert(register);
                 }
             }
+        }
+        members
+            .into_iter()
+            .filter(|(_, (_, registers))| registers.len() > 1)
+            .map(|(locator, (index, _))| (index, locator))
+            .collect()
+    }
+
+    /// Returns the register type of the given register, from the prefetched register types if available.
+    fn get_register_type(&self, stack: &Stack<N>, register: &Register<N>) -> Result<RegisterType<N>> {
+        match self.prefetched_types.get(register) {
+            Some(register_type) => Ok(*register_type),
+            None => {
+                #[cfg(test)]
+                self.num_type_lookups.set(self.num_type_lookups.get() + 1);
+                self.register_types.get_type(stack, register)
+            }
+        }
+    }
+
+    /// Returns the program address of the given program ID, deriving it only on the first call.
+    fn program_address(&self, program_id: &ProgramID<N>) -> Result<Address<N>> {
+        if let Some(address) = self.program_addresses.borrow().get(program_id) {
+            return Ok(*address);
+        }
+        #[cfg(test)]
+        self.num_address_derivations.set(self.num_address_derivations.get() + 1);
+        let address = program_id.to_address()?;
+        self.program_addresses.borrow_mut().insert(*program_id, address);
+        Ok(address)
+    }
+
+    /// Loads the record entry of a given operand from the registers, preserving the entry visibility.
+    ///
+    /// # Errors
+    /// This method will halt if the operand is not a register member of a record.
+    /// This method will halt if the register locator is not found, or if the entry is not found.
+    #[inline]
+    pub fn load_entry(&self, stack: &Stack<N>, operand: &Operand<N>) -> Result<Entry<N, Plaintext<N>>> {
+        // Retrieve the register member.
+        let (register, path) = match operand {
+            Operand::Register(register @ Register::Member(_, path)) => (register, path),
+            _ => bail!("Operand '{operand}' is not a record entry"),
+        };
+
+        // Ensure the register member is found, and matches the register type.
+        self.load(stack, operand)?;
+
+        // Retrieve the record entry from the path.
+        match self.console_registers.get(&register.locator()) {
+            Some(Value::Record(record)) => record.find(path),
+            Some(Value::Plaintext(..)) => bail!("Operand '{operand}' is not a record entry"),
+            None => bail!("'{register}' does not exist"),
+        }
+    }
+
+    /// Loads the record entry of a given operand from the registers, preserving the entry visibility.
+    ///
+    /// # Errors
+    /// This method will halt if the operand is not a register member of a record.
+    /// This method will halt if the register locator is not found, or if the entry is not found.
+    #[inline]
+    pub fn load_entry_circuit(
+        &self,
+        stack: &Stack<N>,
+        operand: &Operand<N>,
+    ) -> Result<circuit::Entry<A, circuit::Plaintext<A>>> {
+        use circuit::Inject;
+
+        // Retrieve the register member.
+        let (register, path) = match operand {
+            Operand::Register(register @ Register::Member(_, path)) => (register, path),
+            _ => bail!("Operand '{operand}' is not a record entry"),
         };
 
-        // Retrieve the register type.
-        match self.register_types.get_type(stack, register) {
-            // Ensure the stack value matches the register type.
-            Ok(register_type) => {
-                stack.matches_register_type(&circuit::Eject::eject_value(&circuit_value), &register_type)?
+        // Ensure the register member is found, and matches the register type.
+        self.load_circuit(stack, operand)?;
+
+        // Retrieve the record entry from the path.
+        match self.circuit_registers.get(&register.locator()) {
+            Some(circuit::Value::Record(record)) => {
+                // Inject the path.
+                let path = path.iter().map(|member| circuit::Identifier::constant(*member)).collect::<Vec<_>>();
+                record.find(&path)
             }
-            // Ensure the register is defined.
-            Err(error) => bail!("Register '{register}' is not a member of the function: {error}"),
+            Some(circuit::Value::Plaintext(..)) => bail!("Operand '{operand}' is not a record entry"),
+            None => bail!("'{register}' does not exist"),
+        }
+    }
+}
+
+/// The projection of a record onto a subset of its entries, where redacted entries are `None`.
+pub type RecordProjection<N> = IndexMap<Identifier<N>, Option<Entry<N, Plaintext<N>>>>;
+
+impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
+    /// Loads the record of a given operand from the registers, keeping only the constant and public entries.
+    /// The owner is included as the `owner` entry, and private entries are redacted as `None`.
+    ///
+    /// # Errors
+    /// This method will halt if the operand is not a register of a record.
+    #[inline]
+    pub fn load_public(&self, stack: &Stack<N>, operand: &Operand<N>) -> Result<RecordProjection<N>> {
+        self.load_projection(stack, operand, |entry| matches!(entry, Entry::Constant(..) | Entry::Public(..)))
+    }
+
+    /// Loads the record of a given operand from the registers, keeping only the private entries.
+    /// The owner is included as the `owner` entry, and constant and public entries are redacted as `None`.
+    ///
+    /// # Errors
+    /// This method will halt if the operand is not a register of a record.
+    #[inline]
+    pub fn load_private(&self, stack: &Stack<N>, operand: &Operand<N>) -> Result<RecordProjection<N>> {
+        self.load_projection(stack, operand, |entry| matches!(entry, Entry::Private(..)))
+    }
+
+    /// Loads the record of a given operand from the registers, redacting the entries that are not selected.
+    fn load_projection(
+        &self,
+        stack: &Stack<N>,
+        operand: &Operand<N>,
+        is_selected: fn(&Entry<N, Plaintext<N>>) -> bool,
+    ) -> Result<RecordProjection<N>> {
+        // Retrieve the record.
+        let record = match self.load(stack, operand)? {
+            Value::Record(record) => record,
+            Value::Plaintext(..) => bail!("Operand '{operand}' is not a record"),
         };
 
-        Ok(circuit_value)
+        // Convert the owner into an entry.
+        let owner = match record.owner() {
+            Owner::Public(address) => Entry::Public(Plaintext::from(Literal::Address(*address))),
+            Owner::Private(plaintext) => Entry::Private(plaintext.clone()),
+        };
+
+        // Redact the entries that are not selected.
+        Ok(std::iter::once((Identifier::from_str("owner")?, owner))
+            .chain(record.data().iter().map(|(identifier, entry)| (*identifier, entry.clone())))
+            .map(|(identifier, entry)| (identifier, is_selected(&entry).then_some(entry)))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{process::test_helpers::sample_process, Authorization, CallStack, Program};
+    use circuit::{network::AleoV0, Eject, Environment, Inject};
+    use console::{account::PrivateKey, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    /// Samples a program with literal, struct, and record inputs.
+    fn sample_program() -> Program<CurrentNetwork> {
+        Program::from_str(
+            r"
+program testing.aleo;
+
+struct point:
+    x as u64;
+    y as u64;
+
+struct line:
+    start as point;
+    end as point;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function run:
+    input r0 as u64.private;
+    input r1 as line.private;
+    input r2 as token.record;
+    add r0 r1.start.x into r3;
+    output r3 as u64.private;",
+        )
+        .unwrap()
+    }
+
+    /// Samples the registers for the `run` function, with both console and circuit values stored.
+    fn sample_registers(
+        stack: &Stack<CurrentNetwork>,
+        rng: &mut TestRng,
+    ) -> Result<(Registers<CurrentNetwork, CurrentAleo>, Address<CurrentNetwork>)> {
+        // Initialize the registers.
+        let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
+            CallStack::evaluate(Authorization::new(&[]))?,
+            stack.get_register_types(&Identifier::from_str("run")?)?.clone(),
+        );
+
+        // Sample the caller.
+        let caller = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng)?)?;
+        registers.set_caller(caller);
+        registers.set_caller_circuit(circuit::Address::new(circuit::Mode::Private, caller));
+
+        // Initialize the console values.
+        let values = [
+            Value::from_str("5u64")?,
+            Value::from_str("{ start: { x: 1u64, y: 2u64 }, end: { x: 3u64, y: 4u64 } }")?,
+            Value::from_str(&format!("{{ owner: {caller}.private, amount: 100u64.private, _nonce: 0group.public }}"))?,
+        ];
+
+        // Store the values in the console and circuit registers.
+        for (locator, value) in values.into_iter().enumerate() {
+            let register = Register::Locator(locator as u64);
+            registers.store(stack, &register, value.clone())?;
+            registers.store_circuit(stack, &register, circuit::Value::new(circuit::Mode::Private, value))?;
+        }
+
+        Ok((registers, caller))
+    }
+
+    #[test]
+    fn test_load_many() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Initialize the stack.
+        let program = sample_program();
+        let process = sample_process(&program);
+        let stack = process.get_stack(program.id())?;
+
+        // Initialize the registers.
+        let (registers, caller) = sample_registers(stack, &mut rng)?;
+
+        // Initialize a mix of literal, register, register member, and caller operands.
+        let operands = vec![
+            Operand::from_str("7u64")?,
+            Operand::from_str("r0")?,
+            Operand::from_str("r1.start.x")?,
+            Operand::from_str("r2.amount")?,
+            Operand::Caller,
+        ];
+        let expected = vec![
+            Value::from_str("7u64")?,
+            Value::from_str("5u64")?,
+            Value::from_str("1u64")?,
+            Value::from_str("100u64")?,
+            Value::from_str(&caller.to_string())?,
+        ];
+
+        // Ensure the console values match the individual loads.
+        let values = registers.load_many(stack, &operands)?;
+        assert_eq!(expected, values);
+        for (operand, value) in operands.iter().zip_eq(&values) {
+            assert_eq!(registers.load(stack, operand)?, *value);
+        }
+
+        // Ensure the circuit values match the console values.
+        let circuit_values = registers.load_many_circuit(stack, &operands)?;
+        assert_eq!(expected, circuit_values.eject_value());
+
+        // Ensure an empty slice of operands loads no values.
+        assert!(registers.load_many(stack, &[])?.is_empty());
+        assert!(registers.load_many_circuit(stack, &[])?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_many_fails_with_operand_index() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Initialize the stack.
+        let program = sample_program();
+        let process = sample_process(&program);
+        let stack = process.get_stack(program.id())?;
+
+        // Initialize the registers.
+        let (registers, _) = sample_registers(stack, &mut rng)?;
+
+        // Initialize the operands, where the third operand refers to an unassigned register.
+        let operands = vec![Operand::from_str("r0")?, Operand::Caller, Operand::from_str("r5")?];
+
+        // Ensure the error indicates the index of the failing operand.
+        let error = registers.load_many(stack, &operands).unwrap_err().to_string();
+        assert!(error.starts_with("Failed to load operand 2 ('r5')"), "{error}");
+        let error = registers.load_many_circuit(stack, &operands).map(|_| ()).unwrap_err().to_string();
+        assert!(error.starts_with("Failed to load operand 2 ('r5')"), "{error}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_many_checks_shared_registers_once() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Initialize the stack.
+        let program = sample_program();
+        let process = sample_process(&program);
+        let stack = process.get_stack(program.id())?;
+
+        // Initialize the operands, where three distinct members of `r1` share a register.
+        let operands = ["r1.start.x", "r1.start.y", "r1.end.x", "r2.amount", "r1.start.x"]
+            .iter()
+            .map(|operand| Operand::from_str(operand))
+            .collect::<Result<Vec<_>>>()?;
+
+        // Load the operands individually.
+        let (registers, _) = sample_registers(stack, &mut rng)?;
+        let expected = operands.iter().map(|operand| registers.load(stack, operand)).collect::<Result<Vec<_>>>()?;
+        assert_eq!(4, registers.num_type_lookups.get());
+
+        // Ensure the console load checks `r1` once as a whole, instead of once per member.
+        let (registers, _) = sample_registers(stack, &mut rng)?;
+        assert_eq!(expected, registers.load_many(stack, &operands)?);
+        assert_eq!(2, registers.num_type_lookups.get());
+        // Ensure the circuit load checks `r1` once as a whole, instead of once per member.
+        assert_eq!(expected, registers.load_many_circuit(stack, &operands)?.eject_value());
+        assert_eq!(4, registers.num_type_lookups.get());
+
+        // Ensure the members of a checked register are not checked again.
+        registers.load(stack, &Operand::from_str("r1.end.y")?)?;
+        assert_eq!(4, registers.num_type_lookups.get());
+
+        // Ensure a missing member of a shared register reports the index of its operand.
+        let (registers, _) = sample_registers(stack, &mut rng)?;
+        let operands = vec![Operand::from_str("r1.start.x")?, Operand::from_str("r1.start.z")?];
+        let error = registers.load_many(stack, &operands).unwrap_err().to_string();
+        assert!(error.starts_with("Failed to load operand 1 ('r1.start.z')"), "{error}");
+        let error = registers.load_many_circuit(stack, &operands).map(|_| ()).unwrap_err().to_string();
+        assert!(error.starts_with("Failed to load operand 1 ('r1.start.z')"), "{error}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_missing_member() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Initialize the stack.
+        let program = sample_program();
+        let process = sample_process(&program);
+        let stack = process.get_stack(program.id())?;
+
+        // Initialize the registers.
+        let (registers, _) = sample_registers(stack, &mut rng)?;
+
+        for (operand, expected) in [
+            // A missing top-level member of a struct.
+            ("r1.middle", "register 'r1.middle': member 'middle' not found in plaintext of type 'line'"),
+            // A missing nested member of a struct.
+            ("r1.start.z", "register 'r1.start.z': member 'z' not found in plaintext of type 'point'"),
+            // A member of a struct member that is a literal.
+            ("r1.start.x.y", "register 'r1.start.x.y': cannot access member 'y' of a literal value in 'r1.start.x'"),
+            // A member of a literal.
+            ("r0.amount", "register 'r0.amount': cannot access member 'amount' of a literal value in 'r0'"),
+            // A missing top-level entry of a record.
+            ("r2.balance", "register 'r2.balance': member 'balance' not found in record of type 'token.record'"),
+            // A member of a record entry that is a literal.
+            (
+                "r2.amount.balance",
+                "register 'r2.amount.balance': cannot access member 'balance' of a literal value in 'r2.amount'",
+            ),
+        ] {
+            let operand = Operand::from_str(operand)?;
+
+            // Ensure the console and circuit loads report the same error.
+            let error = registers.load(stack, &operand).unwrap_err().to_string();
+            assert_eq!(expected, error);
+            let error = registers.load_circuit(stack, &operand).map(|_| ()).unwrap_err().to_string();
+            assert_eq!(expected, error);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_caches_type_checks() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Initialize the stack.
+        let program = sample_program();
+        let process = sample_process(&program);
+        let stack = process.get_stack(program.id())?;
+
+        // Initialize the registers.
+        let (registers, _) = sample_registers(stack, &mut rng)?;
+        assert_eq!(0, registers.num_type_lookups.get());
+
+        for operand in ["r0", "r1.start.x", "r2.amount"] {
+            let operand = Operand::from_str(operand)?;
+
+            // Ensure the first console load performs a type lookup.
+            let lookups = registers.num_type_lookups.get();
+            let first = registers.load(stack, &operand)?;
+            assert_eq!(lookups + 1, registers.num_type_lookups.get());
+            // Ensure the second console load is identical, and skips the type lookup.
+            assert_eq!(first, registers.load(stack, &operand)?);
+            assert_eq!(lookups + 1, registers.num_type_lookups.get());
+
+            // Ensure the first circuit load performs a type lookup.
+            let first_circuit = registers.load_circuit(stack, &operand)?;
+            assert_eq!(lookups + 2, registers.num_type_lookups.get());
+            // Ensure the second circuit load is identical, and skips the type lookup.
+            assert_eq!(first_circuit.eject_value(), registers.load_circuit(stack, &operand)?.eject_value());
+            assert_eq!(first, first_circuit.eject_value());
+            assert_eq!(lookups + 2, registers.num_type_lookups.get());
+        }
+
+        // Ensure a different member of a loaded register performs a type lookup.
+        let lookups = registers.num_type_lookups.get();
+        registers.load(stack, &Operand::from_str("r1.start.y")?)?;
+        assert_eq!(lookups + 1, registers.num_type_lookups.get());
+        Ok(())
+    }
+
+    #[test]
+    fn test_prefetch_types() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Initialize the stack.
+        let program = sample_program();
+        let process = sample_process(&program);
+        let stack = process.get_stack(program.id())?;
+
+        // Initialize a mix of operands, with a repeated register.
+        let operands = ["7u64", "r0", "r1.start.x", "r2.amount", "r0"]
+            .iter()
+            .map(|operand| Operand::from_str(operand))
+            .collect::<Result<Vec<_>>>()?;
+
+        // Load the operands without prefetching.
+        let (registers, _) = sample_registers(stack, &mut rng)?;
+        let expected = registers.load_many(stack, &operands)?;
+        let expected_circuit = registers.load_many_circuit(stack, &operands)?.eject_value();
+        let lookups_without_prefetch = registers.num_type_lookups.get();
+        assert_eq!(6, lookups_without_prefetch);
+
+        // Prefetch the register types, which consults the type table once per distinct register.
+        let (mut registers, _) = sample_registers(stack, &mut rng)?;
+        registers.prefetch_types(stack, &operands)?;
+        assert_eq!(3, registers.num_type_lookups.get());
+
+        // Ensure the loads match, and are served from the prefetched register types.
+        assert_eq!(expected, registers.load_many(stack, &operands)?);
+        assert_eq!(expected_circuit, registers.load_many_circuit(stack, &operands)?.eject_value());
+        assert_eq!(3, registers.num_type_lookups.get());
+        assert!(registers.num_type_lookups.get() < lookups_without_prefetch);
+
+        // Ensure prefetching an undefined register fails.
+        assert!(registers.prefetch_types(stack, &[Operand::from_str("r9")?]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_circuit_unchecked() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Initialize the stack.
+        let program = sample_program();
+        let process = sample_process(&program);
+        let stack = process.get_stack(program.id())?;
+
+        // Initialize the registers.
+        let (registers, _) = sample_registers(stack, &mut rng)?;
+
+        for operand in ["7u64", "r0", "r1", "r1.start.x", "r2", "r2.amount"] {
+            let operand = Operand::from_str(operand)?;
+
+            // Ensure the unchecked load skips the type lookup.
+            let lookups = registers.num_type_lookups.get();
+            let candidate = registers.load_circuit_unchecked(stack, &operand)?;
+            assert_eq!(lookups, registers.num_type_lookups.get());
+            // Ensure the unchecked load matches the checked load.
+            assert_eq!(registers.load_circuit(stack, &operand)?.eject_value(), candidate.eject_value());
+        }
+
+        // Ensure the unchecked load still fails for a missing register.
+        assert!(registers.load_circuit_unchecked(stack, &Operand::from_str("r5")?).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_entry() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Initialize the stack.
+        let program = sample_program();
+        let process = sample_process(&program);
+        let stack = process.get_stack(program.id())?;
+
+        // Initialize the registers.
+        let (registers, _) = sample_registers(stack, &mut rng)?;
+
+        // Ensure the record entry retains its visibility, while `load` returns the same plaintext.
+        let operand = Operand::from_str("r2.amount")?;
+        let expected = Plaintext::from_str("100u64")?;
+        assert_eq!(Entry::Private(expected.clone()), registers.load_entry(stack, &operand)?);
+        assert_eq!(Value::Plaintext(expected.clone()), registers.load(stack, &operand)?);
+
+        // Ensure the circuit record entry retains its visibility.
+        match registers.load_entry_circuit(stack, &operand)? {
+            circuit::Entry::Private(plaintext) => assert_eq!(expected, plaintext.eject_value()),
+            _ => panic!("Expected a private entry"),
+        }
+        assert_eq!(Value::Plaintext(expected), registers.load_circuit(stack, &operand)?.eject_value());
+
+        // Ensure operands that are not record entries fail.
+        for operand in ["5u64", "r0", "r1.start.x", "r2"] {
+            let operand = Operand::from_str(operand)?;
+            assert!(registers.load_entry(stack, &operand).is_err());
+            assert!(registers.load_entry_circuit(stack, &operand).is_err());
+        }
+        assert!(registers.load_entry(stack, &Operand::Caller).is_err());
+        // Ensure a missing record entry fails.
+        assert!(registers.load_entry(stack, &Operand::from_str("r2.balance")?).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_public_and_private() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Initialize a program with a record of mixed visibilities.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+record token:
+    owner as address.public;
+    amount as u64.private;
+    memo as field.public;
+    kind as u8.constant;
+
+function run:
+    input r0 as token.record;
+    input r1 as u64.private;
+    output r1 as u64.private;",
+        )?;
+        let process = sample_process(&program);
+        let stack = process.get_stack(program.id())?;
+
+        // Initialize the registers.
+        let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
+            CallStack::evaluate(Authorization::new(&[]))?,
+            stack.get_register_types(&Identifier::from_str("run")?)?.clone(),
+        );
+        let caller = Address::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+        let record = Value::from_str(&format!(
+            "{{ owner: {caller}.public, amount: 100u64.private, memo: 7field.public, kind: 1u8.constant, _nonce: 0group.public }}"
+        ))?;
+        registers.store(stack, &Register::Locator(0), record)?;
+        registers.store(stack, &Register::Locator(1), Value::from_str("5u64")?)?;
+
+        let owner = Entry::Public(Plaintext::from(Literal::Address(caller)));
+        let amount = Entry::Private(Plaintext::from_str("100u64")?);
+        let memo = Entry::Public(Plaintext::from_str("7field")?);
+        let kind = Entry::Constant(Plaintext::from_str("1u8")?);
+
+        // Ensure the public projection includes the public and constant entries, and redacts the private entries.
+        let operand = Operand::from_str("r0")?;
+        let expected = RecordProjection::from_iter([
+            (Identifier::from_str("owner")?, Some(owner)),
+            (Identifier::from_str("amount")?, None),
+            (Identifier::from_str("memo")?, Some(memo)),
+            (Identifier::from_str("kind")?, Some(kind)),
+        ]);
+        assert_eq!(expected, registers.load_public(stack, &operand)?);
+
+        // Ensure the private projection includes the private entries, and redacts the others.
+        let expected = RecordProjection::from_iter([
+            (Identifier::from_str("owner")?, None),
+            (Identifier::from_str("amount")?, Some(amount)),
+            (Identifier::from_str("memo")?, None),
+            (Identifier::from_str("kind")?, None),
+        ]);
+        assert_eq!(expected, registers.load_private(stack, &operand)?);
+
+        // Ensure operands that are not records fail.
+        for operand in ["r1", "r0.amount", "5u64"] {
+            let operand = Operand::from_str(operand)?;
+            assert!(registers.load_public(stack, &operand).is_err());
+            assert!(registers.load_private(stack, &operand).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_block_height_fails() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Initialize the stack.
+        let program = sample_program();
+        let process = sample_process(&program);
+        let stack = process.get_stack(program.id())?;
+
+        // Initialize the registers.
+        let (registers, _) = sample_registers(stack, &mut rng)?;
+
+        // Ensure the block height cannot be loaded outside of finalize.
+        let expected = BLOCK_HEIGHT_OUTSIDE_FINALIZE;
+        assert_eq!(expected, registers.load(stack, &Operand::BlockHeight).unwrap_err().to_string());
+        let error = registers.load_circuit(stack, &Operand::BlockHeight).map(|_| ()).unwrap_err().to_string();
+        assert_eq!(expected, error);
+
+        // Ensure a function that uses the block height is rejected.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function run:
+    input r0 as u32.private;
+    add r0 block.height into r1;
+    output r1 as u32.private;",
+        )?;
+        let process = sample_process(&sample_program());
+        assert!(Stack::new(&process, &program).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_circuit_reuses_literal_constants() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Initialize the stack.
+        let program = sample_program();
+        let process = sample_process(&program);
+        let stack = process.get_stack(program.id())?;
+
+        // Initialize the registers.
+        let (registers, _) = sample_registers(stack, &mut rng)?;
+
+        for literal in ["7u64", "1field", "true", "-5i8"] {
+            let operand = Operand::from_str(literal)?;
+
+            // Ensure the first load injects the constant.
+            let num_constants = CurrentAleo::num_constants();
+            let first = registers.load_circuit(stack, &operand)?;
+            assert!(CurrentAleo::num_constants() > num_constants);
+
+            // Ensure the subsequent loads reuse the constant.
+            let num_constants = CurrentAleo::num_constants();
+            for _ in 0..3 {
+                let value = registers.load_circuit(stack, &operand)?;
+                assert_eq!(first.eject_value(), value.eject_value());
+                assert_eq!(Value::from_str(literal)?, value.eject_value());
+            }
+            assert_eq!(num_constants, CurrentAleo::num_constants());
+        }
+
+        // Ensure distinct literals with the same value but different types are not conflated.
+        let a = registers.load_circuit(stack, &Operand::from_str("1u8")?)?.eject_value();
+        let b = registers.load_circuit(stack, &Operand::from_str("1u16")?)?.eject_value();
+        assert_eq!(Value::from_str("1u8")?, a);
+        assert_eq!(Value::from_str("1u16")?, b);
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_program_id_derives_address_once() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Initialize the stack.
+        let program = sample_program();
+        let process = sample_process(&program);
+        let stack = process.get_stack(program.id())?;
+
+        // Initialize the registers.
+        let (registers, _) = sample_registers(stack, &mut rng)?;
+        assert_eq!(0, registers.num_address_derivations.get());
+
+        // Ensure repeated console and circuit loads return the program address, which is derived once.
+        let operand = Operand::ProgramID(*program.id());
+        let expected = Value::Plaintext(Plaintext::from(Literal::Address(program.id().to_address()?)));
+        for _ in 0..3 {
+            assert_eq!(expected, registers.load(stack, &operand)?);
+            assert_eq!(expected, registers.load_circuit(stack, &operand)?.eject_value());
+        }
+        assert_eq!(1, registers.num_address_derivations.get());
+
+        // Ensure a different program ID derives its own address.
+        let other = ProgramID::<CurrentNetwork>::from_str("credits.aleo")?;
+        let expected = Value::Plaintext(Plaintext::from(Literal::Address(other.to_address()?)));
+        assert_eq!(expected, registers.load(stack, &Operand::ProgramID(other))?);
+        assert_eq!(expected, registers.load(stack, &Operand::ProgramID(other))?);
+        assert_eq!(2, registers.num_address_derivations.get());
+        Ok(())
     }
 }
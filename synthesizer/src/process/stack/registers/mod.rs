@@ -14,17 +14,47 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod snapshot;
+pub use snapshot::RegisterSnapshot;
+
 mod load;
+mod names;
+mod record;
 mod store;
-
-use crate::{CallStack, Load, LoadCircuit, Operand, RegisterTypes, Stack, Store, StoreCircuit};
+mod weight;
+
+use crate::{
+    BLOCK_HEIGHT_OUTSIDE_FINALIZE,
+    CallStack,
+    Load,
+    LoadCircuit,
+    Operand,
+    RegisterTypes,
+    Stack,
+    Store,
+    StoreCircuit,
+};
 use console::{
     network::prelude::*,
-    program::{Entry, Literal, Plaintext, Register, Value},
-    types::{Address, Field},
+    program::{
+        Entry,
+        EntryType,
+        Identifier,
+        Literal,
+        Owner,
+        Plaintext,
+        ProgramID,
+        Record,
+        Register,
+        RegisterType,
+        Value,
+        ValueType,
+    },
+    types::{Address, Field, Group},
 };
 
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
+use std::cell::RefCell;
 
 #[derive(Clone)]
 pub struct Registers<N: Network, A: circuit::Aleo<Network = N>> {
@@ -32,10 +62,28 @@ pub struct Registers<N: Network, A: circuit::Aleo<Network = N>> {
     call_stack: CallStack<N>,
     /// The mapping of all registers to their defined types.
     register_types: RegisterTypes<N>,
+    /// The mapping of bound input names to their register locators.
+    input_names: IndexMap<Identifier<N>, u64>,
     /// The mapping of assigned console registers to their values.
     console_registers: IndexMap<u64, Value<N>>,
     /// The mapping of assigned circuit registers to their values.
     circuit_registers: IndexMap<u64, circuit::Value<A>>,
+    /// The set of console registers (and register members) that have been loaded and type-checked.
+    console_checked: RefCell<IndexSet<Register<N>>>,
+    /// The set of circuit registers (and register members) that have been loaded and type-checked.
+    circuit_checked: RefCell<IndexSet<Register<N>>>,
+    /// The mapping of prefetched registers (and register members) to their register types.
+    prefetched_types: IndexMap<Register<N>, RegisterType<N>>,
+    /// The mapping of loaded literals (by their operand canonical key) to their circuit constants.
+    circuit_literals: RefCell<IndexMap<String, circuit::Literal<A>>>,
+    /// The mapping of program IDs to their program addresses, which are derived once per program ID.
+    program_addresses: RefCell<IndexMap<ProgramID<N>, Address<N>>>,
+    /// The number of register type lookups performed on the type table.
+    #[cfg(test)]
+    num_type_lookups: std::cell::Cell<usize>,
+    /// The number of program addresses derived from program IDs.
+    #[cfg(test)]
+    num_address_derivations: std::cell::Cell<usize>,
     /// The transition caller.
     caller: Option<Address<N>>,
     /// The transition caller, as a circuit.
@@ -53,8 +101,18 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
         Self {
             call_stack,
             register_types,
+            input_names: IndexMap::new(),
             console_registers: IndexMap::new(),
             circuit_registers: IndexMap::new(),
+            console_checked: Default::default(),
+            circuit_checked: Default::default(),
+            prefetched_types: IndexMap::new(),
+            circuit_literals: Default::default(),
+            program_addresses: Default::default(),
+            #[cfg(test)]
+            num_type_lookups: Default::default(),
+            #[cfg(test)]
+            num_address_derivations: Default::default(),
             caller: None,
             caller_circuit: None,
             tvk: None,
@@ -116,6 +174,55 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
         self.tvk_circuit = Some(tvk_circuit);
     }
 
+    /// Returns an error indicating the first member in the path of the given register that is not found in the value.
+    fn missing_member_error(&self, stack: &Stack<N>, register: &Register<N>, value: &Value<N>) -> Error {
+        // Retrieve the locator and path of the register member.
+        let (locator, path) = match register {
+            Register::Locator(..) => return anyhow!("register '{register}' is not a register member"),
+            Register::Member(locator, path) => (*locator, path),
+        };
+
+        // Find the index of the first member in the path that is not found.
+        let index = (0..path.len())
+            .find(|index| match value {
+                Value::Plaintext(plaintext) => plaintext.find(&path[..=*index]).is_err(),
+                Value::Record(record) => record.find(&path[..=*index]).is_err(),
+            })
+            .unwrap_or(path.len().saturating_sub(1));
+        let member = path.get(index).map(|member| member.to_string()).unwrap_or_default();
+
+        // Determine the parent of the missing member.
+        let (parent, kind) = match (index, value) {
+            (0, Value::Record(..)) => (Register::Locator(locator), "record"),
+            (0, Value::Plaintext(..)) => (Register::Locator(locator), "plaintext"),
+            (_, _) => (Register::Member(locator, path[..index].to_vec()), "plaintext"),
+        };
+
+        // If the parent is a literal, return an error indicating a member cannot be accessed on a literal.
+        let parent_value = match value {
+            Value::Plaintext(plaintext) if index == 0 => Some(plaintext.clone()),
+            Value::Plaintext(plaintext) => plaintext.find(&path[..index]).ok(),
+            Value::Record(..) if index == 0 => None,
+            Value::Record(record) => match record.find(&path[..index]) {
+                Ok(Entry::Constant(plaintext) | Entry::Public(plaintext) | Entry::Private(plaintext)) => {
+                    Some(plaintext)
+                }
+                Err(_) => None,
+            },
+        };
+        if let Some(Plaintext::Literal(..)) = parent_value {
+            return anyhow!("register '{register}': cannot access member '{member}' of a literal value in '{parent}'");
+        }
+
+        // Include the type of the parent, if it is defined.
+        match self.register_types.get_type(stack, &parent) {
+            Ok(parent_type) => {
+                anyhow!("register '{register}': member '{member}' not found in {kind} of type '{parent_type}'")
+            }
+            Err(_) => anyhow!("register '{register}': member '{member}' not found in {kind}"),
+        }
+    }
+
     /// Ensure the console and circuit registers match.
     #[inline]
     pub fn ensure_console_and_circuit_registers_match(&self) -> Result<()> {
@@ -0,0 +1,93 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
+    /// Binds the given names to the input registers, in their declared order.
+    pub fn bind_input_names(&mut self, names: &[Identifier<N>]) -> Result<()> {
+        // Ensure there is one name per input register.
+        let num_inputs = self.register_types.input_locators().len();
+        ensure!(names.len() == num_inputs, "Expected {num_inputs} input names, found {}", names.len());
+        // Map each name to its input register, and ensure there are no duplicate names.
+        let mut input_names = IndexMap::with_capacity(num_inputs);
+        for (name, locator) in names.iter().zip_eq(self.register_types.input_locators()) {
+            ensure!(input_names.insert(*name, locator).is_none(), "Duplicate input name '{name}'");
+        }
+        self.input_names = input_names;
+        Ok(())
+    }
+
+    /// Loads the value of the input register bound to the given name.
+    pub fn load_by_name(&self, stack: &Stack<N>, name: &Identifier<N>) -> Result<Value<N>> {
+        match self.input_names.get(name) {
+            Some(locator) => self.load(stack, &Operand::Register(Register::Locator(*locator))),
+            None => bail!("Input name '{name}' is not bound to a register"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{process::test_helpers::sample_process, Authorization, CallStack, Program};
+    use circuit::network::AleoV0;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    #[test]
+    fn test_load_by_name() -> Result<()> {
+        // Initialize the stack.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function transfer:
+    input r0 as u64.private;
+    input r1 as field.public;
+    add r0 r0 into r2;
+    output r2 as u64.private;",
+        )?;
+        let process = sample_process(&program);
+        let stack = process.get_stack(program.id())?;
+
+        // Initialize the registers, and store the inputs.
+        let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
+            CallStack::evaluate(Authorization::new(&[]))?,
+            stack.get_register_types(&Identifier::from_str("transfer")?)?.clone(),
+        );
+        let (amount, memo) = (Value::<CurrentNetwork>::from_str("100u64")?, Value::from_str("7field")?);
+        registers.store(stack, &Register::Locator(0), amount.clone())?;
+        registers.store(stack, &Register::Locator(1), memo.clone())?;
+
+        // Ensure a mismatched number of names, or a duplicate name, is rejected.
+        let (amount_name, memo_name) = (Identifier::from_str("amount")?, Identifier::from_str("memo")?);
+        assert!(registers.bind_input_names(&[amount_name]).is_err());
+        assert!(registers.bind_input_names(&[amount_name, amount_name]).is_err());
+
+        // Ensure the named inputs are loaded from their registers.
+        registers.bind_input_names(&[amount_name, memo_name])?;
+        assert_eq!(amount, registers.load_by_name(stack, &amount_name)?);
+        assert_eq!(memo, registers.load_by_name(stack, &memo_name)?);
+
+        // Ensure an unknown name is rejected.
+        let error = registers.load_by_name(stack, &Identifier::from_str("fee")?).unwrap_err();
+        assert_eq!("Input name 'fee' is not bound to a register", error.to_string());
+        Ok(())
+    }
+}
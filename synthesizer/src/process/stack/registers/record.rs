@@ -0,0 +1,160 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
+    /// Returns a new record for the given destination register, from the given owner and members.
+    /// Each member is assigned the visibility declared in the record type of the destination register.
+    pub fn build_record(
+        &self,
+        stack: &Stack<N>,
+        destination: &Register<N>,
+        owner: Address<N>,
+        members: Vec<(Identifier<N>, Value<N>)>,
+    ) -> Result<Value<N>> {
+        // Retrieve the record type of the destination register.
+        let record_name = match self.register_types.get_type(stack, destination)? {
+            RegisterType::Record(record_name) => record_name,
+            register_type => bail!("Register '{destination}' is a '{register_type}', expected a record"),
+        };
+        let record_type = stack.program().get_record(&record_name)?;
+
+        // Initialize the record owner.
+        let owner: Owner<N, Plaintext<N>> = match record_type.owner().is_public() {
+            true => Owner::Public(owner),
+            false => Owner::Private(Plaintext::Literal(Literal::Address(owner), Default::default())),
+        };
+
+        // Index the members by name, and ensure there are no duplicate or unknown members.
+        let mut members = members.into_iter().try_fold(IndexMap::new(), |mut members, (name, value)| {
+            ensure!(record_type.entries().contains_key(&name), "Record '{record_name}' has no member '{name}'");
+            ensure!(members.insert(name, value).is_none(), "Duplicate member '{name}' for record '{record_name}'");
+            Ok::<_, Error>(members)
+        })?;
+
+        // Initialize the record entries, in the declared order.
+        let mut entries = IndexMap::with_capacity(record_type.entries().len());
+        for (entry_name, entry_type) in record_type.entries() {
+            // Retrieve the plaintext value of the member.
+            let plaintext = match members.remove(entry_name) {
+                Some(Value::Plaintext(plaintext)) => plaintext,
+                Some(Value::Record(..)) => bail!("Member '{entry_name}' of record '{record_name}' cannot be a record"),
+                None => bail!("Missing member '{entry_name}' for record '{record_name}'"),
+            };
+            // Ensure the member matches the declared type.
+            let register_type = RegisterType::from(ValueType::from(*entry_type));
+            stack.matches_register_type(&Value::Plaintext(plaintext.clone()), &register_type)?;
+            // Assign the declared visibility to the member.
+            let entry = match entry_type {
+                EntryType::Constant(..) => Entry::Constant(plaintext),
+                EntryType::Public(..) => Entry::Public(plaintext),
+                EntryType::Private(..) => Entry::Private(plaintext),
+            };
+            entries.insert(*entry_name, entry);
+        }
+
+        // Compute the nonce for the destination register.
+        let nonce = self.record_nonce(destination)?;
+
+        // Construct the record.
+        Ok(Value::Record(Record::<N, Plaintext<N>>::from_plaintext(owner, entries, nonce)?))
+    }
+
+    /// Returns the nonce of a new record in the given destination register, as `G^HashToScalar(tvk || index)`,
+    /// where `index` is the locator of the destination register.
+    pub fn record_nonce(&self, destination: &Register<N>) -> Result<Group<N>> {
+        // Prepare the index as a field element.
+        let index = Field::from_u64(destination.locator());
+        // Compute the randomizer as `HashToScalar(tvk || index)`.
+        let randomizer = N::hash_to_scalar_psd2(&[self.tvk()?, index])?;
+        // Compute the nonce from the randomizer.
+        Ok(N::g_scalar_multiply(&randomizer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{process::test_helpers::sample_process, Authorization, CallStack, Program};
+    use circuit::network::AleoV0;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    #[test]
+    fn test_build_record() -> Result<()> {
+        // Initialize the stack.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.public;
+    memo as field.private;
+
+function mint:
+    input r0 as address.private;
+    input r1 as u64.public;
+    input r2 as field.private;
+    cast r0 r1 r2 into r3 as token.record;
+    output r3 as token.record;",
+        )?;
+        let process = sample_process(&program);
+        let stack = process.get_stack(program.id())?;
+
+        // Initialize the registers.
+        let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
+            CallStack::evaluate(Authorization::new(&[]))?,
+            stack.get_register_types(&Identifier::from_str("mint")?)?.clone(),
+        );
+        registers.set_tvk(Field::from_u64(1));
+
+        let destination = Register::Locator(3);
+        let owner = Address::from_str("aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah")?;
+        let amount = Identifier::from_str("amount")?;
+        let memo = Identifier::from_str("memo")?;
+        let members = vec![(memo, Value::from_str("7field")?), (amount, Value::from_str("100u64")?)];
+
+        // Ensure the members are assigned the declared visibilities, in the declared order.
+        let record = match registers.build_record(stack, &destination, owner, members.clone())? {
+            Value::Record(record) => record,
+            value => bail!("Expected a record, found '{value}'"),
+        };
+        assert!(record.owner().is_private());
+        assert_eq!(vec![amount, memo], record.data().keys().copied().collect::<Vec<_>>());
+        assert_eq!(&Entry::Public(Plaintext::from_str("100u64")?), record.data().get(&amount).unwrap());
+        assert_eq!(&Entry::Private(Plaintext::from_str("7field")?), record.data().get(&memo).unwrap());
+        // Ensure the nonce is derived from the destination register.
+        assert_eq!(&registers.record_nonce(&destination)?, record.nonce());
+        assert_ne!(registers.record_nonce(&destination)?, registers.record_nonce(&Register::Locator(4))?);
+        // Ensure the record matches the record type of the destination register.
+        stack.matches_register_type(&Value::Record(record), &RegisterType::Record(Identifier::from_str("token")?))?;
+
+        // Ensure a missing, duplicate, or mistyped member is rejected.
+        assert!(registers.build_record(stack, &destination, owner, members[..1].to_vec()).is_err());
+        let mut duplicate = members.clone();
+        duplicate.push((memo, Value::from_str("8field")?));
+        assert!(registers.build_record(stack, &destination, owner, duplicate).is_err());
+        let mistyped = vec![(memo, Value::from_str("7field")?), (amount, Value::from_str("100u32")?)];
+        assert!(registers.build_record(stack, &destination, owner, mistyped).is_err());
+        // Ensure a destination that is not a record is rejected.
+        assert!(registers.build_record(stack, &Register::Locator(1), owner, members).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,137 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A snapshot of the register state, used to restore the registers after an aborted speculative execution.
+#[derive(Clone)]
+pub struct RegisterSnapshot<N: Network, A: circuit::Aleo<Network = N>> {
+    /// The mapping of bound input names to their register locators.
+    input_names: IndexMap<Identifier<N>, u64>,
+    /// The mapping of assigned console registers to their values.
+    console_registers: IndexMap<u64, Value<N>>,
+    /// The mapping of assigned circuit registers to their values.
+    circuit_registers: IndexMap<u64, circuit::Value<A>>,
+    /// The set of console registers (and register members) that have been loaded and type-checked.
+    console_checked: IndexSet<Register<N>>,
+    /// The set of circuit registers (and register members) that have been loaded and type-checked.
+    circuit_checked: IndexSet<Register<N>>,
+    /// The mapping of prefetched registers (and register members) to their register types.
+    prefetched_types: IndexMap<Register<N>, RegisterType<N>>,
+    /// The mapping of loaded literals (by their operand canonical key) to their circuit constants.
+    circuit_literals: IndexMap<String, circuit::Literal<A>>,
+}
+
+impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
+    /// Returns a snapshot of the assigned registers and their caches.
+    /// Note: The call stack, register types, caller, and transition view key are not captured,
+    /// as they are fixed for the duration of a transition.
+    #[inline]
+    pub fn snapshot(&self) -> RegisterSnapshot<N, A> {
+        RegisterSnapshot {
+            input_names: self.input_names.clone(),
+            console_registers: self.console_registers.clone(),
+            circuit_registers: self.circuit_registers.clone(),
+            console_checked: self.console_checked.borrow().clone(),
+            circuit_checked: self.circuit_checked.borrow().clone(),
+            prefetched_types: self.prefetched_types.clone(),
+            circuit_literals: self.circuit_literals.borrow().clone(),
+        }
+    }
+
+    /// Restores the assigned registers and their caches from the given snapshot.
+    #[inline]
+    pub fn restore(&mut self, snapshot: RegisterSnapshot<N, A>) {
+        self.input_names = snapshot.input_names;
+        self.console_registers = snapshot.console_registers;
+        self.circuit_registers = snapshot.circuit_registers;
+        self.console_checked = RefCell::new(snapshot.console_checked);
+        self.circuit_checked = RefCell::new(snapshot.circuit_checked);
+        self.prefetched_types = snapshot.prefetched_types;
+        self.circuit_literals = RefCell::new(snapshot.circuit_literals);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Authorization, Program, process::test_helpers::sample_process};
+    use circuit::{Eject, Inject, network::AleoV0};
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    #[test]
+    fn test_snapshot_and_restore() -> Result<()> {
+        // Initialize the stack.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function run:
+    input r0 as u64.private;
+    input r1 as u64.private;
+    add r0 r1 into r2;
+    output r2 as u64.private;",
+        )?;
+        let process = sample_process(&program);
+        let stack = process.get_stack(program.id())?;
+
+        // Initialize the registers.
+        let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
+            CallStack::evaluate(Authorization::new(&[]))?,
+            stack.get_register_types(&Identifier::from_str("run")?)?.clone(),
+        );
+
+        // Store the inputs.
+        let store = |registers: &mut Registers<CurrentNetwork, CurrentAleo>, locator: u64, value: &str| {
+            let value = Value::from_str(value)?;
+            registers.store(stack, &Register::Locator(locator), value.clone())?;
+            registers.store_circuit(
+                stack,
+                &Register::Locator(locator),
+                circuit::Value::new(circuit::Mode::Private, value),
+            )
+        };
+        store(&mut registers, 0, "1u64")?;
+        store(&mut registers, 1, "2u64")?;
+
+        // Load the original values, and take a snapshot.
+        let operands = [Operand::from_str("r0")?, Operand::from_str("r1")?];
+        let expected = registers.load_many(stack, &operands)?;
+        let expected_circuit = registers.load_many_circuit(stack, &operands)?.eject_value();
+        let snapshot = registers.snapshot();
+
+        // Speculatively assign the output, and load it.
+        store(&mut registers, 2, "3u64")?;
+        assert_eq!(Value::from_str("3u64")?, registers.load(stack, &Operand::from_str("r2")?)?);
+
+        // Ensure restoring the snapshot reproduces the original loads.
+        registers.restore(snapshot);
+        assert_eq!(expected, registers.load_many(stack, &operands)?);
+        assert_eq!(expected_circuit, registers.load_many_circuit(stack, &operands)?.eject_value());
+        // Ensure the output register assigned after the snapshot is no longer assigned.
+        assert!(registers.load(stack, &Operand::from_str("r2")?).is_err());
+        assert!(registers.load_circuit(stack, &Operand::from_str("r2")?).is_err());
+
+        // Ensure the output register can be assigned again.
+        store(&mut registers, 2, "7u64")?;
+        assert_eq!(Value::from_str("7u64")?, registers.load(stack, &Operand::from_str("r2")?)?);
+        assert_eq!(Value::from_str("7u64")?, registers.load_circuit(stack, &Operand::from_str("r2")?)?.eject_value());
+        Ok(())
+    }
+}
@@ -44,6 +44,9 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Store<N> for Registers<N, A> {
                     Err(error) => bail!("Register '{register}' is missing a type definition: {error}"),
                 };
 
+                // Invalidate any cached type checks for the register.
+                self.console_checked.get_mut().retain(|checked| checked.locator() != *locator);
+
                 // Store the stack value.
                 match self.console_registers.insert(*locator, stack_value) {
                     // Ensure the register has not been previously stored.
@@ -93,6 +96,9 @@ impl<N: Network, A: circuit::Aleo<Network = N>> StoreCircuit<N, A> for Registers
                     Err(error) => bail!("Register '{register}' is missing a type definition: {error}"),
                 };
 
+                // Invalidate any cached type checks for the register.
+                self.circuit_checked.get_mut().retain(|checked| checked.locator() != *locator);
+
                 // Store the stack value.
                 match self.circuit_registers.insert(*locator, circuit_value) {
                     // Ensure the register has not been previously stored.
@@ -106,3 +112,89 @@ impl<N: Network, A: circuit::Aleo<Network = N>> StoreCircuit<N, A> for Registers
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Authorization, CallStack, Program, process::test_helpers::sample_process};
+    use circuit::{Eject, Inject, network::AleoV0};
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    #[test]
+    fn test_store_and_load() -> Result<()> {
+        // Initialize the stack.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+struct point:
+    x as u64;
+    y as u64;
+
+function run:
+    input r0 as u64.private;
+    input r1 as point.private;
+    add r0 r1.x into r2;
+    output r2 as u64.private;",
+        )?;
+        let process = sample_process(&program);
+        let stack = process.get_stack(program.id())?;
+
+        // Initialize the registers.
+        let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
+            CallStack::evaluate(Authorization::new(&[]))?,
+            stack.get_register_types(&Identifier::from_str("run")?)?.clone(),
+        );
+
+        // Ensure the stored values are loaded back identically.
+        for (locator, value) in ["5u64", "{ x: 1u64, y: 2u64 }", "6u64"].into_iter().enumerate() {
+            let register = Register::Locator(locator as u64);
+            let value = Value::<CurrentNetwork>::from_str(value)?;
+            registers.store(stack, &register, value.clone())?;
+            registers.store_circuit(stack, &register, circuit::Value::new(circuit::Mode::Private, value.clone()))?;
+
+            let operand = Operand::Register(register);
+            assert_eq!(value, registers.load(stack, &operand)?);
+            assert_eq!(value, registers.load_circuit(stack, &operand)?.eject_value());
+        }
+
+        // Ensure a write to an undefined register fails.
+        let register = Register::Locator(3);
+        let value = Value::<CurrentNetwork>::from_str("7u64")?;
+        assert!(registers.store(stack, &register, value.clone()).is_err());
+        assert!(registers.store_circuit(stack, &register, circuit::Value::new(circuit::Mode::Private, value)).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_store_fails_on_type_mismatch() -> Result<()> {
+        // Initialize the stack.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function run:
+    input r0 as u64.private;
+    output r0 as u64.private;",
+        )?;
+        let process = sample_process(&program);
+        let stack = process.get_stack(program.id())?;
+
+        // Initialize the registers.
+        let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
+            CallStack::evaluate(Authorization::new(&[]))?,
+            stack.get_register_types(&Identifier::from_str("run")?)?.clone(),
+        );
+
+        // Ensure a value of the wrong type is rejected, and the register remains unassigned.
+        let register = Register::Locator(0);
+        let value = Value::<CurrentNetwork>::from_str("5u32")?;
+        assert!(registers.store(stack, &register, value.clone()).is_err());
+        assert!(registers.store_circuit(stack, &register, circuit::Value::new(circuit::Mode::Private, value)).is_err());
+        assert!(registers.load(stack, &Operand::Register(register)).is_err());
+        Ok(())
+    }
+}
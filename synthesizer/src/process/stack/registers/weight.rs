@@ -0,0 +1,185 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
+    /// Returns the estimated weight of loading the given operand from the registers, without loading it.
+    ///   - A literal is free, as it is injected as a constant.
+    ///   - The program ID and caller each weigh `1`.
+    ///   - A register weighs `1` for the lookup, plus `1` for each member in its path,
+    ///     plus `1` for each literal in the value it refers to.
+    ///
+    /// # Errors
+    /// This method will halt if the register locator is not found.
+    /// In the case of register members, this method will halt if the member is not found.
+    pub fn load_weight(&self, stack: &Stack<N>, operand: &Operand<N>) -> Result<u64> {
+        // Retrieve the register.
+        let register = match operand {
+            // If the operand is a literal, it is free to load.
+            Operand::Literal(..) => return Ok(0),
+            // If the operand is a register, estimate the weight below.
+            Operand::Register(register) => register,
+            // If the operand is the program ID or the caller, it is loaded directly.
+            Operand::ProgramID(..) | Operand::Caller => return Ok(1),
+            // If the operand is the block height, throw an error.
+            Operand::BlockHeight => bail!(BLOCK_HEIGHT_OUTSIDE_FINALIZE),
+        };
+
+        // Retrieve the stack value.
+        let stack_value =
+            self.console_registers.get(&register.locator()).ok_or_else(|| anyhow!("'{register}' does not exist"))?;
+
+        // Count the literals in the value for the given register or register member.
+        let num_literals = match register {
+            // If the register is a locator, then count the literals in the stack value.
+            Register::Locator(..) => match stack_value {
+                Value::Plaintext(plaintext) => num_literals(plaintext),
+                // Note: The owner is counted as a single literal.
+                Value::Record(record) => record
+                    .data()
+                    .values()
+                    .fold(1u64, |sum, entry| sum.saturating_add(num_literals(entry_plaintext(entry)))),
+            },
+            // If the register is a register member, then count the literals in the specific stack value.
+            Register::Member(_, ref path) => {
+                let plaintext = match stack_value {
+                    Value::Plaintext(plaintext) => plaintext.find(path),
+                    Value::Record(record) => record.find(path).map(|entry| entry_plaintext(&entry).clone()),
+                };
+                match plaintext {
+                    Ok(plaintext) => num_literals(&plaintext),
+                    Err(_) => bail!(self.missing_member_error(stack, register, stack_value)),
+                }
+            }
+        };
+
+        // Retrieve the depth of the path.
+        let depth = match register {
+            Register::Locator(..) => 0,
+            Register::Member(_, path) => path.len() as u64,
+        };
+
+        // Return the weight.
+        Ok(1u64.saturating_add(depth).saturating_add(num_literals))
+    }
+}
+
+/// Returns the plaintext of the given entry.
+fn entry_plaintext<N: Network>(entry: &Entry<N, Plaintext<N>>) -> &Plaintext<N> {
+    match entry {
+        Entry::Constant(plaintext) | Entry::Public(plaintext) | Entry::Private(plaintext) => plaintext,
+    }
+}
+
+/// Returns the number of literals in the given plaintext.
+fn num_literals<N: Network>(plaintext: &Plaintext<N>) -> u64 {
+    match plaintext {
+        Plaintext::Literal(..) => 1,
+        Plaintext::Struct(members, ..) => {
+            members.values().fold(0u64, |sum, member| sum.saturating_add(num_literals(member)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Authorization, CallStack, Program, process::test_helpers::sample_process};
+    use circuit::network::AleoV0;
+    use console::{account::PrivateKey, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    #[test]
+    fn test_load_weight() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Initialize the stack.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+struct point:
+    x as u64;
+    y as u64;
+
+struct line:
+    start as point;
+    end as point;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+    path as line.private;
+
+function run:
+    input r0 as u64.private;
+    input r1 as token.record;
+    output r0 as u64.private;",
+        )?;
+        let process = sample_process(&program);
+        let stack = process.get_stack(program.id())?;
+
+        // Initialize the registers.
+        let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
+            CallStack::evaluate(Authorization::new(&[]))?,
+            stack.get_register_types(&Identifier::from_str("run")?)?.clone(),
+        );
+        let caller = Address::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+        registers.set_caller(caller);
+        registers.store(stack, &Register::Locator(0), Value::from_str("5u64")?)?;
+        registers.store(
+            stack,
+            &Register::Locator(1),
+            Value::from_str(&format!(
+                "{{ owner: {caller}.private, amount: 100u64.private, path: {{ start: {{ x: 1u64.private, y: 2u64.private }}, end: {{ x: 3u64.private, y: 4u64.private }} }}, _nonce: 0group.public }}"
+            ))?,
+        )?;
+
+        for (operand, expected) in [
+            // A literal is free.
+            ("7u64", 0),
+            // A top-level register of a literal.
+            ("r0", 2),
+            // A top-level register of a record, with an owner and 5 literals.
+            ("r1", 7),
+            // A record member of a literal.
+            ("r1.amount", 3),
+            // A record member of a struct, with 4 literals.
+            ("r1.path", 6),
+            // A deep record member, with 3 members in its path.
+            ("r1.path.start.x", 5),
+        ] {
+            assert_eq!(expected, registers.load_weight(stack, &Operand::from_str(operand)?)?, "{operand}");
+        }
+        assert_eq!(1, registers.load_weight(stack, &Operand::Caller)?);
+
+        // Ensure a literal weighs less than a top-level register, which weighs less than a deep record member.
+        let literal = registers.load_weight(stack, &Operand::from_str("5u64")?)?;
+        let register = registers.load_weight(stack, &Operand::from_str("r0")?)?;
+        let member = registers.load_weight(stack, &Operand::from_str("r1.path.start.x")?)?;
+        assert!(literal < register && register < member);
+
+        // Ensure the weight fails when the load would fail.
+        assert!(registers.load_weight(stack, &Operand::from_str("r2")?).is_err());
+        assert!(registers.load_weight(stack, &Operand::from_str("r1.balance")?).is_err());
+        assert!(registers.load_weight(stack, &Operand::from_str("r1.path.middle")?).is_err());
+        assert!(registers.load_weight(stack, &Operand::BlockHeight).is_err());
+        Ok(())
+    }
+}
@@ -25,6 +25,22 @@ pub trait Load<N: Network> {
     /// In the case of register members, this method should halt if the member is not found.
     fn load(&self, stack: &Stack<N>, operand: &Operand<N>) -> Result<Value<N>>;
 
+    /// Loads the values of the given operands, in order.
+    ///
+    /// # Errors
+    /// This method should halt if any of the operands fails to load, indicating the index of the operand.
+    #[inline]
+    fn load_many(&self, stack: &Stack<N>, operands: &[Operand<N>]) -> Result<Vec<Value<N>>> {
+        operands
+            .iter()
+            .enumerate()
+            .map(|(index, operand)| {
+                self.load(stack, operand)
+                    .map_err(|error| anyhow!("Failed to load operand {index} ('{operand}'): {error}"))
+            })
+            .collect()
+    }
+
     /// Loads the literal of a given operand.
     ///
     /// # Errors
@@ -63,6 +79,22 @@ pub trait LoadCircuit<N: Network, A: circuit::Aleo<Network = N>> {
     /// In the case of register members, this method should halt if the member is not found.
     fn load_circuit(&self, stack: &Stack<N>, operand: &Operand<N>) -> Result<circuit::Value<A>>;
 
+    /// Loads the values of the given operands, in order.
+    ///
+    /// # Errors
+    /// This method should halt if any of the operands fails to load, indicating the index of the operand.
+    #[inline]
+    fn load_many_circuit(&self, stack: &Stack<N>, operands: &[Operand<N>]) -> Result<Vec<circuit::Value<A>>> {
+        operands
+            .iter()
+            .enumerate()
+            .map(|(index, operand)| {
+                self.load_circuit(stack, operand)
+                    .map_err(|error| anyhow!("Failed to load operand {index} ('{operand}'): {error}"))
+            })
+            .collect()
+    }
+
     /// Loads the literal of a given operand.
     ///
     /// # Errors
@@ -22,6 +22,8 @@ use console::{
     program::{Literal, ProgramID, Register},
 };
 
+use std::collections::BTreeSet;
+
 /// The `Operand` enum represents the options for an operand in an instruction.
 /// This enum is designed to for instructions such as `add {Register} {Literal} into {Register}`.
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -34,6 +36,9 @@ pub enum Operand<N: Network> {
     ProgramID(ProgramID<N>),
     /// The operand is the caller address.
     Caller,
+    /// The operand is the block height.
+    /// Note: This operand is only accessible in the `finalize` scope.
+    BlockHeight,
 }
 
 impl<N: Network> From<Literal<N>> for Operand<N> {
@@ -68,6 +73,43 @@ impl<N: Network> From<&Register<N>> for Operand<N> {
     }
 }
 
+impl<N: Network> Operand<N> {
+    /// Returns a stable key for comparing operands, such as for caching and deduplication.
+    /// An empty member path of a register addresses the entire value, so that `r0` and an empty member path
+    /// of `r0` share a key.
+    /// Note: The key is the printed operand, which is unambiguous, as each variant prints in a distinct form.
+    pub fn canonical_key(&self) -> String {
+        match self {
+            Self::Register(Register::Member(locator, path)) if path.is_empty() => {
+                Register::<N>::Locator(*locator).to_string()
+            }
+            _ => self.to_string(),
+        }
+    }
+}
+
+/// The error message for a `block.height` operand outside of the `finalize` scope.
+pub(crate) const BLOCK_HEIGHT_OUTSIDE_FINALIZE: &str =
+    "Forbidden operation: Cannot use 'block.height' outside of 'finalize'";
+
+/// Returns the distinct locators of the register operands, in ascending order.
+/// Note: Literal, program ID, caller, and block height operands do not reference a register.
+pub fn referenced_locators<N: Network>(operands: &[Operand<N>]) -> BTreeSet<u64> {
+    operands
+        .iter()
+        .filter_map(|operand| match operand {
+            Operand::Register(register) => Some(register.locator()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns `true` if every operand is known at compile time, i.e. a literal or a program ID.
+/// Note: Register, caller, and block height operands are only known at runtime, and an empty slice returns `true`.
+pub fn all_constant<N: Network>(operands: &[Operand<N>]) -> bool {
+    operands.iter().all(|operand| matches!(operand, Operand::Literal(..) | Operand::ProgramID(..)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -104,4 +146,68 @@ mod tests {
         assert_eq!(expected, operand);
         Ok(())
     }
+
+    #[test]
+    fn test_canonical_key() -> Result<()> {
+        // Ensure equal operands share a key.
+        for operand in ["1field", "5u8", "r0", "r1.owner", "credits.aleo", "self.caller", "block.height"] {
+            let a = Operand::<CurrentNetwork>::from_str(operand)?;
+            let b = Operand::<CurrentNetwork>::from_str(operand)?;
+            assert_eq!(a.canonical_key(), b.canonical_key());
+            assert_eq!(operand, a.canonical_key());
+        }
+
+        // Ensure a register and its empty member path share a key.
+        let locator = Operand::<CurrentNetwork>::Register(Register::Locator(0));
+        let member = Operand::<CurrentNetwork>::Register(Register::Member(0, vec![]));
+        assert_ne!(locator, member);
+        assert_eq!(locator.canonical_key(), member.canonical_key());
+
+        // Ensure distinct operands have distinct keys.
+        let operands = ["1field", "1u8", "1u16", "r0", "r1", "r0.owner", "credits.aleo", "self.caller", "block.height"]
+            .iter()
+            .map(|operand| Operand::<CurrentNetwork>::from_str(operand))
+            .collect::<Result<Vec<_>>>()?;
+        let keys = operands.iter().map(|operand| operand.canonical_key()).collect::<BTreeSet<_>>();
+        assert_eq!(operands.len(), keys.len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_referenced_locators() -> Result<()> {
+        let operands =
+            ["r3", "1field", "r0.owner", "self.caller", "r3.amount", "r0", "block.height", "r1", "credits.aleo"]
+                .iter()
+                .map(|operand| Operand::<CurrentNetwork>::from_str(operand))
+                .collect::<Result<Vec<_>>>()?;
+
+        // Ensure each distinct locator is returned once, in ascending order.
+        let locators = referenced_locators(&operands);
+        assert_eq!(vec![0, 1, 3], locators.into_iter().collect::<Vec<_>>());
+
+        // Ensure operands without registers reference no locators.
+        assert!(referenced_locators(&operands[1..2]).is_empty());
+        assert!(referenced_locators::<CurrentNetwork>(&[]).is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_all_constant() -> Result<()> {
+        let parse = |operands: &[&str]| {
+            operands.iter().map(|operand| Operand::<CurrentNetwork>::from_str(operand)).collect::<Result<Vec<_>>>()
+        };
+
+        // Ensure literals and program IDs are constant.
+        assert!(all_constant(&parse(&["1field", "5u8", "credits.aleo", "true"])?));
+        assert!(all_constant(&parse(&["credits.aleo"])?));
+        assert!(all_constant::<CurrentNetwork>(&[]));
+
+        // Ensure any register, caller, or block height operand is not constant.
+        for runtime in ["r0", "r1.owner", "self.caller", "block.height"] {
+            assert!(!all_constant(&parse(&[runtime])?));
+            assert!(!all_constant(&parse(&["1field", runtime, "credits.aleo"])?));
+            assert!(!all_constant(&parse(&[runtime, "5u8"])?));
+        }
+        Ok(())
+    }
 }
@@ -187,12 +187,8 @@ impl<N: Network> Cast<N> {
                     };
                 }
 
-                // Prepare the index as a field element.
-                let index = Field::from_u64(self.destination.locator());
-                // Compute the randomizer as `HashToScalar(tvk || index)`.
-                let randomizer = N::hash_to_scalar_psd2(&[registers.tvk()?, index])?;
-                // Compute the nonce from the randomizer.
-                let nonce = N::g_scalar_multiply(&randomizer);
+                // Compute the nonce for the destination register.
+                let nonce = registers.record_nonce(&self.destination)?;
 
                 // Construct the record.
                 let record = Record::<N, Plaintext<N>>::from_plaintext(owner, entries, nonce)?;
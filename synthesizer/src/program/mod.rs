@@ -37,7 +37,7 @@ mod serialize;
 
 use console::{
     network::prelude::*,
-    program::{EntryType, Identifier, PlaintextType, ProgramID, RecordType, Struct},
+    program::{EntryType, Identifier, LiteralType, PlaintextType, ProgramID, RecordType, Struct},
 };
 
 use indexmap::IndexMap;
@@ -365,7 +365,11 @@ impl<N: Network> Program<N> {
                 PlaintextType::Struct(member_identifier) => {
                     // Ensure the member struct name exists in the program.
                     if !self.structs.contains_key(member_identifier) {
-                        bail!("'{member_identifier}' in struct '{}' is not defined.", struct_name)
+                        bail!(
+                            "'{member_identifier}' in struct '{}' is not defined. {}",
+                            struct_name,
+                            Self::expected_member_types()
+                        )
                     }
                 }
             }
@@ -415,7 +419,10 @@ impl<N: Network> Program<N> {
                     PlaintextType::Literal(..) => continue,
                     PlaintextType::Struct(identifier) => {
                         if !self.structs.contains_key(identifier) {
-                            bail!("Struct '{identifier}' in record '{record_name}' is not defined.")
+                            bail!(
+                                "Struct '{identifier}' in record '{record_name}' is not defined. {}",
+                                Self::expected_member_types()
+                            )
                         }
                     }
                 },
@@ -433,6 +440,12 @@ impl<N: Network> Program<N> {
         Ok(())
     }
 
+    /// Returns the message listing the valid member types, for an undefined member type.
+    fn expected_member_types() -> String {
+        let literal_types = LiteralType::all().map(|type_| type_.to_string()).collect::<Vec<_>>().join(", ");
+        format!("Expected a literal type ({literal_types}), or a struct that is defined earlier in the program.")
+    }
+
     /// Adds a new closure to the program.
     ///
     /// # Errors
@@ -687,6 +700,37 @@ struct message:
         Ok(())
     }
 
+    #[test]
+    fn test_program_record_member_types() -> Result<()> {
+        // Ensure a record accepts each literal type as a member type.
+        let entries = LiteralType::all().map(|type_| format!("\n    m_{type_} as {type_}.public;")).collect::<String>();
+        let record =
+            RecordType::<CurrentNetwork>::from_str(&format!("record foo:\n    owner as address.private;{entries}"))?;
+        let mut program = Program::<CurrentNetwork>::new(ProgramID::from_str("unknown.aleo")?)?;
+        program.add_record(record)?;
+
+        // Ensure a record with a group member is accepted.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program unknown.aleo;
+
+record foo:
+    owner as address.private;
+    point as group.public;",
+        )?;
+        assert!(program.contains_record(&Identifier::from_str("foo")?));
+
+        // Ensure a record with an unknown member type is rejected, and the error lists the valid types.
+        let record = RecordType::<CurrentNetwork>::from_str(
+            "record foo:\n    owner as address.private;\n    point as frobnicate.public;",
+        )?;
+        let mut program = Program::<CurrentNetwork>::new(ProgramID::from_str("unknown.aleo")?)?;
+        let error = program.add_record(record).unwrap_err().to_string();
+        assert!(error.contains("Struct 'frobnicate' in record 'foo' is not defined"), "{error}");
+        assert!(error.contains("address, boolean, field, group, i8"), "{error}");
+        Ok(())
+    }
+
     #[test]
     fn test_program_record() -> Result<()> {
         // Create a new record.
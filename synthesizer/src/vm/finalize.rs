@@ -17,10 +17,10 @@
 use super::*;
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
-    /// Finalizes the given transactions into the VM.
+    /// Finalizes the given transactions into the VM, for the block at the given height.
     /// This method assumes the given transactions **are valid**.
     #[inline]
-    pub fn finalize(&self, transactions: &Transactions<N>) -> Result<()> {
+    pub fn finalize(&self, transactions: &Transactions<N>, block_height: u32) -> Result<()> {
         let timer = timer!("VM::finalize");
         atomic_write_batch!(self, {
             // Acquire the write lock on the process.
@@ -34,7 +34,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                         lap!(timer, "Finalize deployment");
                     }
                     Transaction::Execute(_, execution, _) => {
-                        process.finalize_execution(self.program_store(), execution)?;
+                        process.finalize_execution(self.program_store(), execution, block_height)?;
                         lap!(timer, "Finalize execution");
                     }
                 }
@@ -63,9 +63,9 @@ mod tests {
         let deployment_transaction = crate::vm::test_helpers::sample_deployment_transaction(rng);
 
         // Finalize the transaction.
-        vm.finalize(&Transactions::from(&[deployment_transaction.clone()])).unwrap();
+        vm.finalize(&Transactions::from(&[deployment_transaction.clone()]), 1).unwrap();
 
         // Ensure the VM can't redeploy the same transaction.
-        assert!(vm.finalize(&Transactions::from(&[deployment_transaction])).is_err());
+        assert!(vm.finalize(&Transactions::from(&[deployment_transaction]), 1).is_err());
     }
 }
@@ -86,7 +86,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         // First, insert the block.
         self.block_store().insert(block)?;
         // Next, finalize the transactions.
-        match self.finalize(block.transactions()) {
+        match self.finalize(block.transactions(), block.height()) {
             Ok(_) => Ok(()),
             Err(error) => {
                 // Rollback the block.